@@ -15,6 +15,14 @@ fn generate_key(generator: &Generator<MyHasher>) {
     generator.generate(1235761289);
 }
 
+fn generate_keys_in_loop(generator: &Generator<MyHasher>) -> Vec<LicenseKey> {
+    (0..1000).map(|seed| generator.generate(seed)).collect()
+}
+
+fn generate_keys_in_batch(generator: &Generator<MyHasher>) -> Vec<LicenseKey> {
+    generator.generate_batch(0..1000)
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     let generator = Generator::new(
         MyHasher::default(),
@@ -26,6 +34,12 @@ fn criterion_benchmark(c: &mut Criterion) {
         ],
     );
     c.bench_function("generate_key", |b| b.iter(|| generate_key(&generator)));
+    c.bench_function("generate_keys_in_loop", |b| {
+        b.iter(|| generate_keys_in_loop(&generator))
+    });
+    c.bench_function("generate_keys_in_batch", |b| {
+        b.iter(|| generate_keys_in_batch(&generator))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use license_key::*;
+
+#[derive(Default)]
+struct MyHasher {}
+impl KeyHasher for MyHasher {
+    fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+        // For demonstrational purposes only.
+        // You need to implement your own secret sauce.
+        ((seed ^ a ^ b ^ c) & 0xFF) as u8
+    }
+}
+
+fn verify_key(verifier: &Verifier<MyHasher>, key: &LicenseKey) -> Status {
+    verifier.verify(key)
+}
+
+fn verify_keys_in_loop(verifier: &Verifier<MyHasher>, keys: &[LicenseKey]) -> Vec<Status> {
+    keys.iter().map(|key| verifier.verify(key)).collect()
+}
+
+fn verify_keys_in_stream(verifier: &Verifier<MyHasher>, keys: &[LicenseKey]) -> Vec<Status> {
+    keys.iter()
+        .map(|key| {
+            let mut stream = verifier.stream();
+            stream.feed(key.as_bytes());
+            stream.finish()
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let generator = Generator::new(
+        MyHasher::default(),
+        vec![
+            (112344, 812393, 175439050),
+            (64234200, 223423408, 253485347),
+            (653453459, 153454, 2534502),
+            (675331, 23452, 553453454),
+        ],
+    );
+    let verifier = Verifier::new(
+        MyHasher::default(),
+        vec![
+            ByteCheck::new(0, (112344, 812393, 175439050)),
+            ByteCheck::new(2, (653453459, 153454, 2534502)),
+        ],
+    );
+    let key = generator.generate(1235761289);
+    let keys = generator.generate_batch(0..1000);
+    c.bench_function("verify_key", |b| b.iter(|| verify_key(&verifier, &key)));
+    c.bench_function("verify_keys_in_loop", |b| {
+        b.iter(|| verify_keys_in_loop(&verifier, &keys))
+    });
+    c.bench_function("verify_keys_in_stream", |b| {
+        b.iter(|| verify_keys_in_stream(&verifier, &keys))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -24,5 +24,5 @@ pub fn main() {
     // Write the key information to the console.
     println!("Generated key");
     println!("-------------");
-    println!("{}", key.serialize::<HexFormat>());
+    println!("{}", key.serialize(&HexFormat));
 }
@@ -25,11 +25,12 @@ pub fn main() {
     verifier.block(11111111_u64);
 
     // Verify a license key.
-    let key = LicenseKey::parse::<HexFormat>("112210F4B2D230A229552341E723");
+    let key = LicenseKey::parse("112210F4B2D230A229552341E723", &HexFormat).unwrap();
     match verifier.verify(&key) {
         Status::Valid => println!("Key is valid!"),
         Status::Invalid => println!("Key is invalid!"),
         Status::Blocked => println!("Key has been blocked!"),
         Status::Forged => println!("Key has been forged!"),
+        _ => println!("Key could not be verified!"),
     }
 }
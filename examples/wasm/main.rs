@@ -0,0 +1,39 @@
+use license_key::*;
+
+// Build the verifier for the browser with:
+//
+//   wasm-pack build --target web -- --features wasm
+//
+// and call it from JavaScript:
+//
+//   import init, { WasmVerifier } from "./pkg/license_key.js";
+//
+//   await init();
+//   const verifier = WasmVerifier.fromConfig(config);
+//   console.log(verifier.verifyAt(key, Date.now() / 1000));
+//
+// This example calls the same functions natively.
+pub fn main() {
+    // The public parameters of the verifier.
+    // The generator and the rest of the initialization vector stay on the server.
+    let config = "
+        secret 736563726574
+        check 0 114 83 170
+        block 11111111
+    ";
+    let verifier = WasmVerifier::from_config(config).expect("the configuration is valid");
+
+    // Generate a license key like the server would.
+    let generator = Generator::new(
+        HmacHasher::new(b"secret"),
+        vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)],
+    );
+    let key = generator.generate(1234567891011121314_u64);
+
+    // Verify the license key at the current time.
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("the time is after the Unix epoch")
+        .as_secs_f64();
+    println!("{}", verifier.verify_at(&key.serialize(&HexFormat), now));
+}
@@ -0,0 +1,315 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, Grouped, LicenseKey, Serializer};
+
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RFC4648_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// License key serializer for Crockford base32 strings.
+///
+/// The alphabet doesn't contain the letters I, L, O and U, which makes
+/// the license keys easier to read and dictate. When deserializing,
+/// the case is ignored, hyphens are skipped, and the letters I and L
+/// are read as `1` while O is read as `0`.
+pub struct Base32Format;
+impl Serializer for Base32Format {
+    fn case_insensitive(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        encode(key.as_bytes(), ALPHABET)
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        let mut output = Vec::new();
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+        for (index, character) in input.chars().enumerate() {
+            if character == '-' {
+                continue;
+            }
+            let value = match decode_character(character) {
+                Some(value) => value,
+                None => return Err(DeserializeError::InvalidCharacter { character, index }),
+            };
+            buffer = (buffer << 5) | value as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                output.push((buffer >> bits) as u8);
+            }
+        }
+
+        if output.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+
+        Ok(output)
+    }
+}
+
+/// License key serializer for Crockford base32 strings in groups of five
+/// characters separated by dashes, such as `ABCDE-FGHIJ-KLMNO`.
+///
+/// This is meant for license keys that are read aloud, such as over the
+/// phone. Just like [`Base32Format`], the case is ignored when
+/// deserializing, dashes are skipped, and the letters I and L are read
+/// as `1` while O is read as `0`.
+///
+/// [`Base32Format`]: struct.Base32Format.html
+pub struct VoiceFriendlyFormat;
+impl Serializer for VoiceFriendlyFormat {
+    fn case_insensitive(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        Grouped::new(Base32Format, 5, '-').serialize(key)
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        Base32Format.deserialize(input)
+    }
+}
+
+/// License key serializer for standard RFC 4648 base32 strings,
+/// using the alphabet `A`–`Z` and `2`–`7`.
+///
+/// Use this format to interoperate with other systems that expect
+/// standard base32. The output is padded with `=` to a multiple of eight
+/// characters if padding is enabled. When deserializing, both padded and
+/// unpadded input is accepted and the case is ignored.
+pub struct Rfc4648Base32Format {
+    padding: bool,
+}
+
+impl Rfc4648Base32Format {
+    /// Creates a new RFC 4648 base32 serializer that pads
+    /// the output with `=` if `padding` is `true`.
+    pub fn new(padding: bool) -> Self {
+        Self { padding }
+    }
+}
+
+impl Serializer for Rfc4648Base32Format {
+    fn case_insensitive(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        let mut output = encode(key.as_bytes(), RFC4648_ALPHABET);
+        if self.padding {
+            while !output.len().is_multiple_of(8) {
+                output.push('=');
+            }
+        }
+        output
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        let mut output = Vec::new();
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+        let data = input.trim_end_matches('=');
+        for (index, character) in data.chars().enumerate() {
+            let value = RFC4648_ALPHABET
+                .iter()
+                .position(|c| *c as char == character.to_ascii_uppercase());
+            let value = match value {
+                Some(value) => value,
+                None => return Err(DeserializeError::InvalidCharacter { character, index }),
+            };
+            buffer = (buffer << 5) | value as u32;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                output.push((buffer >> bits) as u8);
+            }
+        }
+
+        if output.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+
+        Ok(output)
+    }
+}
+
+fn encode(bytes: &[u8], alphabet: &[u8; 32]) -> String {
+    let mut output = String::new();
+    let mut buffer = 0_u32;
+    let mut bits = 0;
+    for byte in bytes.iter() {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            output.push(alphabet[((buffer >> bits) & 0x1F) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        output.push(alphabet[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+    }
+    output
+}
+
+fn decode_character(character: char) -> Option<u8> {
+    let character = match character.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        character => character,
+    };
+    ALPHABET
+        .iter()
+        .position(|c| *c as char == character)
+        .map(|position| position as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_key;
+    use crate::KeyLayout;
+
+    #[test]
+    pub fn base32_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = LicenseKey::parse(&key.serialize(&Base32Format), &Base32Format).unwrap();
+
+        // Then
+        assert_eq!(key.get_bytes(), result.get_bytes());
+    }
+
+    #[test]
+    pub fn base32_should_serialize_to_uppercase() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = key.serialize(&Base32Format);
+
+        // Then
+        assert_eq!(result.to_ascii_uppercase(), result);
+        assert_eq!(23, result.len());
+    }
+
+    #[test]
+    pub fn base32_should_ignore_case_and_hyphens() {
+        // Given
+        let key = generate_key(12345);
+        let canonical = key.serialize(&Base32Format);
+        let typed = format!("{}-{}", &canonical[..5], &canonical[5..]).to_ascii_lowercase();
+
+        // When
+        let result = Base32Format.deserialize(&typed);
+
+        // Then
+        assert_eq!(Base32Format.deserialize(&canonical), result);
+    }
+
+    #[test]
+    pub fn base32_should_reject_invalid_characters() {
+        // Given, When
+        let result = Base32Format.deserialize("ABCU");
+
+        // Then
+        let expected = DeserializeError::InvalidCharacter {
+            character: 'U',
+            index: 3,
+        };
+        assert_eq!(Err(expected), result);
+    }
+
+    #[test]
+    pub fn rfc4648_base32_should_round_trip_with_and_without_padding() {
+        for padding in [true, false].iter() {
+            // Given
+            let format = Rfc4648Base32Format::new(*padding);
+            let key = generate_key(12345);
+
+            // When
+            let serialized = key.serialize(&format);
+            let result = LicenseKey::parse(&serialized, &format).unwrap();
+
+            // Then
+            assert_eq!(key, result);
+            assert_eq!(*padding, serialized.ends_with('='));
+        }
+    }
+
+    #[test]
+    pub fn rfc4648_base32_should_match_rfc_test_vector() {
+        // Given
+        let key = LicenseKey::new(b"foobar".to_vec(), KeyLayout::default());
+
+        // When
+        let padded = key.serialize(&Rfc4648Base32Format::new(true));
+        let unpadded = key.serialize(&Rfc4648Base32Format::new(false));
+
+        // Then
+        assert_eq!("MZXW6YTBOI======", padded);
+        assert_eq!("MZXW6YTBOI", unpadded);
+        assert_eq!(
+            Ok(b"foobar".to_vec()),
+            Rfc4648Base32Format::new(false).deserialize(&padded)
+        );
+        assert_eq!(
+            Ok(b"foobar".to_vec()),
+            Rfc4648Base32Format::new(true).deserialize("mzxw6ytboi")
+        );
+    }
+
+    #[test]
+    pub fn rfc4648_base32_should_reject_invalid_characters() {
+        for (input, character, index) in [("MZX1", '1', 3), ("MZ=W6", '=', 2)].iter() {
+            // Given, When
+            let result = Rfc4648Base32Format::new(true).deserialize(input);
+
+            // Then
+            let expected = DeserializeError::InvalidCharacter {
+                character: *character,
+                index: *index,
+            };
+            assert_eq!(Err(expected), result);
+        }
+    }
+
+    #[test]
+    pub fn voice_friendly_format_should_round_trip_in_groups_of_five() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let serialized = key.serialize(&VoiceFriendlyFormat);
+        let result = LicenseKey::parse(&serialized, &VoiceFriendlyFormat).unwrap();
+
+        // Then
+        assert_eq!(key, result);
+        assert!(serialized
+            .split('-')
+            .rev()
+            .skip(1)
+            .all(|group| group.len() == 5));
+        assert_eq!(serialized.replace('-', ""), key.serialize(&Base32Format));
+    }
+
+    #[test]
+    pub fn voice_friendly_format_should_accept_ambiguous_characters() {
+        // Given
+        let key = generate_key(0);
+        let serialized = key.serialize(&VoiceFriendlyFormat);
+        let typed = serialized.replacen('0', "O", 3);
+
+        // When
+        let result = LicenseKey::parse(&typed, &VoiceFriendlyFormat).unwrap();
+
+        // Then
+        assert!(serialized.starts_with("00000-"));
+        assert_eq!(key, result);
+    }
+}
@@ -0,0 +1,177 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, LicenseKey, Serializer, MAX_KEY_LENGTH};
+
+// Every byte takes at most log(256) / log(58) (about 1.37) characters
+const MAX_INPUT_LENGTH: usize = MAX_KEY_LENGTH * 138 / 100 + 1;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// License key serializer for Base58 strings, using the Bitcoin alphabet.
+///
+/// The alphabet doesn't contain the characters `0`, `O`, `I` and `l`,
+/// nor any symbols, which means that the license keys can be selected
+/// with a double click and are hard to misread. Unlike [`Base32Format`],
+/// the case of the characters matters.
+///
+/// Decoding takes time that grows with the square of the length of the
+/// input, so input that is longer than any license key can be is rejected
+/// with [`DeserializeError::TooLong`] before it's decoded.
+///
+/// [`Base32Format`]: struct.Base32Format.html
+/// [`DeserializeError::TooLong`]: enum.DeserializeError.html#variant.TooLong
+pub struct Base58Format;
+impl Serializer for Base58Format {
+    fn serialize(&self, key: &LicenseKey) -> String {
+        let bytes = key.as_bytes();
+
+        // Every leading zero byte is encoded as the first character
+        let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+        // Convert the bytes into base58 digits (least significant first)
+        let mut digits: Vec<u8> = Vec::new();
+        for byte in bytes[zeros..].iter() {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        let mut output = String::with_capacity(zeros + digits.len());
+        for _ in 0..zeros {
+            output.push(ALPHABET[0] as char);
+        }
+        for digit in digits.iter().rev() {
+            output.push(ALPHABET[*digit as usize] as char);
+        }
+        output
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        if input.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+        if input.len() > MAX_INPUT_LENGTH {
+            return Err(DeserializeError::TooLong {
+                length: input.len(),
+                maximum: MAX_INPUT_LENGTH,
+            });
+        }
+
+        let mut zeros = 0;
+        let mut leading = true;
+
+        // Convert the base58 digits into bytes (least significant first)
+        let mut output: Vec<u8> = Vec::new();
+        for (index, character) in input.chars().enumerate() {
+            let value = match decode_character(character) {
+                Some(value) => value,
+                None => return Err(DeserializeError::InvalidCharacter { character, index }),
+            };
+            if leading && value == 0 {
+                zeros += 1;
+                continue;
+            }
+            leading = false;
+
+            let mut carry = value as u32;
+            for byte in output.iter_mut() {
+                carry += *byte as u32 * 58;
+                *byte = carry as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                output.push(carry as u8);
+                carry >>= 8;
+            }
+        }
+
+        output.resize(output.len() + zeros, 0);
+        output.reverse();
+        Ok(output)
+    }
+}
+
+fn decode_character(character: char) -> Option<u8> {
+    ALPHABET
+        .iter()
+        .position(|c| *c as char == character)
+        .map(|position| position as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_key;
+    use crate::KeyLayout;
+
+    #[test]
+    pub fn base58_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = LicenseKey::parse(&key.serialize(&Base58Format), &Base58Format).unwrap();
+
+        // Then
+        assert_eq!(key.get_bytes(), result.get_bytes());
+    }
+
+    #[test]
+    pub fn base58_should_keep_leading_zero_bytes() {
+        // Given
+        let key = LicenseKey::new(vec![0, 0, 0x28, 0x7F, 0xB4, 0xCD], KeyLayout::default());
+
+        // When
+        let serialized = key.serialize(&Base58Format);
+        let result = Base58Format.deserialize(&serialized);
+
+        // Then
+        assert_eq!("11233QC4", serialized);
+        assert_eq!(Ok(key.get_bytes()), result);
+    }
+
+    #[test]
+    pub fn base58_should_reject_ambiguous_characters() {
+        for (input, character) in [("2Nf0", '0'), ("2NfO", 'O'), ("2Nfl", 'l')].iter() {
+            // Given, When
+            let result = Base58Format.deserialize(input);
+
+            // Then
+            let expected = DeserializeError::InvalidCharacter {
+                character: *character,
+                index: 3,
+            };
+            assert_eq!(Err(expected), result);
+        }
+    }
+
+    #[test]
+    pub fn base58_should_reject_input_longer_than_any_key() {
+        // Given
+        let longest = LicenseKey::new(vec![0xFF; MAX_KEY_LENGTH], KeyLayout::default());
+        let serialized = longest.serialize(&Base58Format);
+        let input = "2".repeat(MAX_INPUT_LENGTH + 1);
+
+        // When
+        let result = Base58Format.deserialize(&input);
+
+        // Then
+        let expected = DeserializeError::TooLong {
+            length: MAX_INPUT_LENGTH + 1,
+            maximum: MAX_INPUT_LENGTH,
+        };
+        assert_eq!(Err(expected), result);
+        assert_eq!(
+            Ok(longest.get_bytes()),
+            Base58Format.deserialize(&serialized)
+        );
+    }
+}
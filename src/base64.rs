@@ -0,0 +1,141 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, LicenseKey, Serializer};
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// License key serializer for URL-safe Base64 strings without padding.
+///
+/// The output can be embedded in URLs and QR codes without escaping,
+/// and is a third shorter than a hex string. The case of the
+/// characters matters, and padding characters (`=`) are rejected.
+pub struct Base64UrlFormat;
+impl Serializer for Base64UrlFormat {
+    fn case_insensitive(&self) -> bool {
+        // Upper and lower case letters are different digits
+        false
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        let mut output = String::new();
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+        for byte in key.as_bytes().iter() {
+            buffer = (buffer << 8) | *byte as u32;
+            bits += 8;
+            while bits >= 6 {
+                bits -= 6;
+                output.push(ALPHABET[((buffer >> bits) & 0x3F) as usize] as char);
+            }
+        }
+        if bits > 0 {
+            output.push(ALPHABET[((buffer << (6 - bits)) & 0x3F) as usize] as char);
+        }
+        output
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        let mut output = Vec::new();
+        let mut buffer = 0_u32;
+        let mut bits = 0;
+        for (index, character) in input.chars().enumerate() {
+            let value = match decode_character(character) {
+                Some(value) => value,
+                None => return Err(DeserializeError::InvalidCharacter { character, index }),
+            };
+            buffer = (buffer << 6) | value as u32;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                output.push((buffer >> bits) as u8);
+            }
+        }
+
+        if output.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+
+        Ok(output)
+    }
+}
+
+fn decode_character(character: char) -> Option<u8> {
+    ALPHABET
+        .iter()
+        .position(|c| *c as char == character)
+        .map(|position| position as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_verifier, generate_key};
+    use crate::{HexFormat, KeyLayout, Status};
+
+    #[test]
+    pub fn base64_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let serialized = key.serialize(&Base64UrlFormat);
+        let result = LicenseKey::parse(&serialized, &Base64UrlFormat).unwrap();
+
+        // Then
+        assert_eq!(key.get_bytes(), result.get_bytes());
+        assert!(serialized.len() < key.serialize(&HexFormat).len());
+    }
+
+    #[test]
+    pub fn base64_should_use_url_safe_alphabet() {
+        // Given
+        let key = LicenseKey::new(vec![0xFB, 0xFF, 0xBF], KeyLayout::default());
+
+        // When
+        let result = key.serialize(&Base64UrlFormat);
+
+        // Then
+        assert_eq!("-_-_", result);
+    }
+
+    #[test]
+    pub fn base64_should_reject_invalid_characters() {
+        for (input, character) in [("AB+C", '+'), ("AB/C", '/'), ("ABC=", '=')].iter() {
+            // Given, When
+            let result = Base64UrlFormat.deserialize(input);
+
+            // Then
+            let expected = DeserializeError::InvalidCharacter {
+                character: *character,
+                index: input.find(*character).unwrap(),
+            };
+            assert_eq!(Err(expected), result);
+        }
+    }
+
+    #[test]
+    pub fn base64_with_wrong_case_should_be_rejected() {
+        // Given
+        let key = generate_key(12345);
+        let serialized = key.serialize(&Base64UrlFormat);
+        let swapped: String = serialized
+            .chars()
+            .map(|c| {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            })
+            .collect();
+
+        // When
+        let result = create_verifier().verify_str(&swapped, &Base64UrlFormat);
+
+        // Then
+        assert!(!Base64UrlFormat.case_insensitive());
+        assert_ne!(Ok(key.get_bytes()), Base64UrlFormat.deserialize(&swapped));
+        assert_eq!(Status::Invalid, result);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::Set;
+
+/// Represents a list of blocked seeds that a [`Verifier`] consults.
+///
+/// Implement this trait to keep the blocked seeds somewhere else than
+/// in memory, such as in a database, and use the implementation with
+/// [`Verifier::with_blocklist`]. Blocked ranges, prefixes and devices
+/// are still kept by the verifier itself.
+///
+/// [`Verifier`]: struct.Verifier.html
+/// [`Verifier::with_blocklist`]: struct.Verifier.html#method.with_blocklist
+pub trait BlockList {
+    /// Checks whether or not the specified seed has been blocked.
+    fn contains(&self, seed: u64) -> bool;
+}
+
+/// An in-memory [`BlockList`], which is the blocklist that a [`Verifier`]
+/// uses by default. Seeds are blocked and unblocked using the verifier,
+/// such as with [`Verifier::block`] and [`Verifier::unblock`].
+///
+/// [`BlockList`]: trait.BlockList.html
+/// [`Verifier`]: struct.Verifier.html
+/// [`Verifier::block`]: struct.Verifier.html#method.block
+/// [`Verifier::unblock`]: struct.Verifier.html#method.unblock
+#[derive(Debug, Default, Clone)]
+pub struct MemoryBlockList {
+    seeds: Set<u64>,
+}
+
+impl MemoryBlockList {
+    pub(crate) fn insert(&mut self, seed: u64) {
+        self.seeds.insert(seed);
+    }
+
+    pub(crate) fn remove(&mut self, seed: u64) -> bool {
+        self.seeds.remove(&seed)
+    }
+
+    pub(crate) fn retain(&mut self, retain: impl Fn(u64) -> bool) {
+        self.seeds.retain(|seed| retain(*seed));
+    }
+
+    pub(crate) fn extend(&mut self, seeds: impl IntoIterator<Item = u64>) {
+        self.seeds.extend(seeds);
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        self.seeds.iter().copied()
+    }
+}
+
+impl BlockList for MemoryBlockList {
+    fn contains(&self, seed: u64) -> bool {
+        self.seeds.contains(&seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{generate_key, TestHasher};
+    use crate::{ByteCheck, Status, Verifier};
+
+    #[derive(Default)]
+    struct EvenBlockList {
+        lookups: core::cell::Cell<usize>,
+    }
+
+    impl BlockList for EvenBlockList {
+        fn contains(&self, seed: u64) -> bool {
+            self.lookups.set(self.lookups.get() + 1);
+            seed & 1 == 0
+        }
+    }
+
+    #[test]
+    pub fn verifier_should_consult_custom_blocklist() {
+        // Given
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(2, (69, 14, 202)),
+        ];
+        let verifier =
+            Verifier::with_blocklist(TestHasher::default(), checks, EvenBlockList::default());
+
+        // When
+        let even = verifier.verify(&generate_key(12344));
+        let odd = verifier.verify(&generate_key(12345));
+
+        // Then
+        assert_eq!(Status::Blocked, even);
+        assert_eq!(Status::Valid, odd);
+        assert!(verifier.is_blocked(12344));
+        assert_eq!(3, verifier.blocklist().lookups.get());
+    }
+
+    #[test]
+    pub fn memory_blocklist_should_contain_blocked_seeds() {
+        // Given
+        let mut blocklist = MemoryBlockList::default();
+
+        // When
+        blocklist.insert(12345);
+        blocklist.extend(vec![67890]);
+        let removed = blocklist.remove(67890);
+
+        // Then
+        assert!(removed);
+        assert!(blocklist.contains(12345));
+        assert!(!blocklist.contains(67890));
+    }
+}
@@ -0,0 +1,314 @@
+use alloc::vec::Vec;
+
+use crate::ChecksumState;
+
+/// Represents the checksum algorithm that is used to quickly check
+/// whether or not a license key is valid at all.
+///
+/// The generator and the verifier must use the same algorithm, or every
+/// license key will be invalid. The output must be 2 bytes long, or 4 bytes
+/// long for a long checksum, which is what decides the length of the
+/// checksum of the generated license keys.
+/// See [`Generator::use_checksum`] and [`Verifier::use_checksum`].
+///
+/// [`Generator::use_checksum`]: struct.Generator.html#method.use_checksum
+/// [`Verifier::use_checksum`]: struct.Verifier.html#method.use_checksum
+pub trait Checksum: Send + Sync {
+    /// Computes the checksum of the provided bytes.
+    fn compute(&self, data: &[u8]) -> Vec<u8>;
+}
+
+impl core::fmt::Debug for dyn Checksum {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Checksum")
+    }
+}
+
+/// The 2-byte checksum that is used unless another [`Checksum`] is set.
+///
+/// [`Checksum`]: trait.Checksum.html
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultChecksum;
+
+impl Checksum for DefaultChecksum {
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        let mut checksum = ChecksumState::new();
+        for byte in data.iter() {
+            checksum.update(*byte);
+        }
+        checksum.finish().to_vec()
+    }
+}
+
+/// The 2-byte default checksum, but with other initial sums than
+/// the default ones (`0x56` and `0xAF`).
+///
+/// Use this to make sure that license keys for another product built on
+/// this crate never pass the checksum of yours by accident. The generator
+/// and the verifier must use the same initial sums.
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// let mut generator = Generator::new(DummyHasher {}, vec![(1, 2, 3)]);
+/// generator.use_checksum(SeededChecksum::new(0x12, 0x34));
+/// let key = generator.generate(1234567891011121314_u64);
+///
+/// let mut verifier = Verifier::new(DummyHasher {}, vec![ByteCheck::new(0, (1, 2, 3))]);
+/// verifier.use_checksum(SeededChecksum::new(0x12, 0x34));
+/// assert_eq!(Status::Valid, verifier.verify(&key));
+/// ```
+///
+/// [`Checksum`]: trait.Checksum.html
+#[derive(Debug, Clone, Copy)]
+pub struct SeededChecksum {
+    left: u8,
+    right: u8,
+}
+
+impl SeededChecksum {
+    /// Creates a new checksum that starts from the specified sums.
+    pub fn new(left: u8, right: u8) -> Self {
+        Self { left, right }
+    }
+}
+
+impl Default for SeededChecksum {
+    fn default() -> Self {
+        Self::new(0x56, 0xAF)
+    }
+}
+
+impl Checksum for SeededChecksum {
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        let mut checksum = ChecksumState::with_seeds(self.left, self.right);
+        for byte in data.iter() {
+            checksum.update(*byte);
+        }
+        checksum.finish().to_vec()
+    }
+}
+
+/// A standard 2-byte Fletcher-16 [`Checksum`].
+///
+/// The default checksum is a Fletcher-16 variant that starts from
+/// non-zero sums, and that keeps a sum of 255 instead of reducing
+/// it to 0. Both sums are always kept within 0..=255, but this
+/// checksum is useful to interoperate with other systems.
+///
+/// [`Checksum`]: trait.Checksum.html
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Fletcher16;
+
+impl Checksum for Fletcher16 {
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        let mut left = 0_u16;
+        let mut right = 0_u16;
+        for byte in data.iter() {
+            right = (right + *byte as u16) % 255;
+            left = (left + right) % 255;
+        }
+        [left as u8, right as u8].to_vec()
+    }
+}
+
+/// A 2-byte CRC-16/CCITT-FALSE [`Checksum`].
+///
+/// [`Checksum`]: trait.Checksum.html
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Crc16;
+
+impl Checksum for Crc16 {
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        let mut crc = 0xFFFF_u16;
+        for byte in data.iter() {
+            crc ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+        }
+        crc.to_be_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier, generate_key};
+    use crate::{HexFormat, KeyLayout, LicenseKey, Status};
+
+    struct LongChecksum;
+
+    impl Checksum for LongChecksum {
+        fn compute(&self, data: &[u8]) -> Vec<u8> {
+            let mut checksum = Crc16.compute(data);
+            checksum.extend(Fletcher16.compute(data));
+            checksum
+        }
+    }
+
+    #[test]
+    pub fn crc16_should_match_reference_value() {
+        // Given, When
+        let result = Crc16.compute(b"123456789");
+
+        // Then
+        assert_eq!(vec![0x29, 0xB1], result);
+    }
+
+    #[test]
+    pub fn fletcher16_should_match_reference_values() {
+        // Given, When, Then
+        assert_eq!(vec![0xC8, 0xF0], Fletcher16.compute(b"abcde"));
+        assert_eq!(vec![0x20, 0x57], Fletcher16.compute(b"abcdef"));
+        assert_eq!(vec![0x06, 0x27], Fletcher16.compute(b"abcdefgh"));
+    }
+
+    #[test]
+    pub fn default_checksum_should_stay_in_range_for_long_input() {
+        // Given
+        let data = vec![0xFF_u8; 100_000];
+        let mut state = ChecksumState::new();
+
+        for byte in data.iter() {
+            // When
+            state.update(*byte);
+
+            // Then
+            assert!(state.left <= 0xFF && state.right <= 0xFF);
+        }
+        assert_eq!(DefaultChecksum.compute(&data), state.finish().to_vec());
+    }
+
+    #[test]
+    pub fn default_checksum_should_be_congruent_to_fletcher16() {
+        // Given
+        let data: Vec<u8> = (0..10_000_u32).map(|i| (i * 7 + 200) as u8).collect();
+        let mut left = 0x56_u32;
+        let mut right = 0xAF_u32;
+        for byte in data.iter() {
+            right = (right + *byte as u32) % 255;
+            left = (left + right) % 255;
+        }
+
+        // When
+        let result = DefaultChecksum.compute(&data);
+
+        // Then
+        assert_eq!(left, result[0] as u32 % 255);
+        assert_eq!(right, result[1] as u32 % 255);
+    }
+
+    #[test]
+    pub fn default_checksum_should_match_builtin_checksum() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.use_checksum(DefaultChecksum);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn key_with_same_checksum_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_checksum(Crc16);
+        let mut verifier = create_verifier();
+        verifier.use_checksum(Crc16);
+
+        // When
+        let result = verifier.verify(&generator.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn custom_checksum_should_decide_checksum_length() {
+        // Given
+        let mut short = create_generator();
+        short.use_checksum(Crc16);
+        short.use_long_checksum(true);
+        let mut long = create_generator();
+        long.use_checksum(LongChecksum);
+        let mut short_verifier = create_verifier();
+        short_verifier.use_checksum(Crc16);
+        let mut long_verifier = create_verifier();
+        long_verifier.use_checksum(LongChecksum);
+
+        // When
+        let short = short.generate(12345);
+        let long = long.generate(12345);
+
+        // Then
+        assert!(!short.layout().long_checksum);
+        assert!(long.layout().long_checksum);
+        assert_eq!(Status::Valid, short_verifier.verify(&short));
+        assert_eq!(Status::Valid, long_verifier.verify(&long));
+        let layout = KeyLayout {
+            long_checksum: true,
+            ..KeyLayout::default()
+        };
+        let parsed =
+            LicenseKey::parse_with_layout(&long.serialize(&HexFormat), &HexFormat, layout).unwrap();
+        assert_eq!(Status::Valid, long_verifier.verify(&parsed));
+    }
+
+    #[test]
+    pub fn key_with_other_checksum_should_be_invalid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_checksum(Crc16);
+        let key = generator.generate(12345);
+
+        // When
+        let result = create_verifier().verify(&key);
+
+        // Then
+        assert_eq!(Status::Invalid, result);
+    }
+
+    #[test]
+    pub fn default_seeded_checksum_should_match_default_checksum() {
+        // Given
+        let data: Vec<u8> = (0..1000_u32).map(|i| (i * 13) as u8).collect();
+
+        // When
+        let result = SeededChecksum::default().compute(&data);
+
+        // Then
+        assert_eq!(DefaultChecksum.compute(&data), result);
+    }
+
+    #[test]
+    pub fn key_with_custom_checksum_seeds_should_be_invalid_for_defaults() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_checksum(SeededChecksum::new(0x12, 0x34));
+        let key = generator.generate(12345);
+        let mut verifier = create_verifier();
+        verifier.use_checksum(SeededChecksum::new(0x12, 0x34));
+
+        // When
+        let default = create_verifier().verify(&key);
+        let custom = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Invalid, default);
+        assert_eq!(Status::Valid, custom);
+    }
+}
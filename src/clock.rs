@@ -0,0 +1,102 @@
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Represents a source of the current time.
+///
+/// Time-based checks, such as expiration, get the current time from
+/// a clock instead of reading the system time directly, which makes
+/// it possible to control the current time in tests.
+/// See [`Verifier::use_clock`].
+///
+/// [`Verifier::use_clock`]: struct.Verifier.html#method.use_clock
+pub trait Clock: Send + Sync {
+    /// Gets the current time as a Unix timestamp.
+    fn now_unix(&self) -> u64;
+}
+
+impl core::fmt::Debug for dyn Clock {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Clock")
+    }
+}
+
+/// A [`Clock`] that reads the system time.
+///
+/// If the system time is before the Unix epoch, `0` is returned.
+/// The system time can't be read on `wasm32-unknown-unknown`, so
+/// implement a [`Clock`] that calls `Date.now()` in the browser instead.
+///
+/// [`Clock`]: trait.Clock.html
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now_unix(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+/// A [`Clock`] that always returns the same time.
+///
+/// [`Clock`]: trait.Clock.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_unix(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier};
+    use crate::Status;
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn system_clock_should_be_after_epoch() {
+        // Given, When
+        let result = SystemClock.now_unix();
+
+        // Then
+        assert!(result > 1_600_000_000);
+    }
+
+    #[test]
+    pub fn verifier_should_use_clock_for_expiry() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_800_000_000);
+        let mut past = create_verifier();
+        past.use_clock(FixedClock(1_700_000_000));
+        let mut future = create_verifier();
+        future.use_clock(FixedClock(1_900_000_000));
+
+        // When
+        let before = past.verify(&key);
+        let after = future.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, before);
+        assert_eq!(Status::Expired, after);
+    }
+
+    #[test]
+    pub fn verifier_without_clock_should_ignore_expiry() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 0);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+}
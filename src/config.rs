@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+
+use crate::{ByteCheck, Generator, KeyHasher, Verifier};
+
+/// The public parameters of a generator and a verifier, which can be
+/// loaded from a configuration file instead of being hardcoded.
+///
+/// The hasher (and any secret it uses) isn't part of the configuration,
+/// and is still supplied in code. Requires the `serde` feature.
+///
+/// The initialization vector is only needed by the generator, and anyone
+/// who has it can generate license keys. It must never be included in a
+/// configuration that ships with the software, where only the checks
+/// should be present.
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// // Only on the server
+/// let generator_config: Config = serde_json::from_str(r#"{
+///     "iv": [[1, 2, 3], [4, 5, 6]]
+/// }"#).unwrap();
+///
+/// // Ships with the software
+/// let verifier_config: Config = serde_json::from_str(r#"{
+///     "checks": [{ "ordinal": 1, "a": 4, "b": 5, "c": 6 }]
+/// }"#).unwrap();
+///
+/// let generator = Generator::from_config(DummyHasher {}, &generator_config);
+/// let verifier = Verifier::from_config(DummyHasher {}, &verifier_config);
+///
+/// let key = generator.generate(1234567891011121314_u64);
+/// assert_eq!(Status::Valid, verifier.verify(&key));
+/// ```
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Config {
+    /// The initialization vector of the generator.
+    ///
+    /// Leave this out of any configuration that verifiers are created from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub iv: Vec<(u64, u64, u64)>,
+    /// The byte checks of the verifier.
+    #[serde(default)]
+    pub checks: Vec<ByteCheck>,
+}
+
+impl<T: KeyHasher> Generator<T> {
+    /// Creates a new license key generator with the specified hasher
+    /// and the initialization vector in the configuration.
+    pub fn from_config(hasher: T, config: &Config) -> Self {
+        Generator::new(hasher, config.iv.clone())
+    }
+}
+
+impl<T: KeyHasher> Verifier<T> {
+    /// Creates a new license key verifier with the specified hasher and
+    /// the byte checks in the configuration.
+    ///
+    /// Only the byte checks are used. The initialization vector, if any,
+    /// is ignored and never kept by the verifier. See [`Config`].
+    ///
+    /// [`Config`]: struct.Config.html
+    pub fn from_config(hasher: T, config: &Config) -> Self {
+        Verifier::new(hasher, config.checks.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier, TestHasher};
+    use crate::Status;
+
+    const CONFIG: &str = r#"{
+        "iv": [[114, 83, 170], [60, 208, 27], [69, 14, 202], [61, 232, 54]],
+        "checks": [
+            { "ordinal": 0, "a": 114, "b": 83, "c": 170 },
+            { "ordinal": 2, "a": 69, "b": 14, "c": 202 }
+        ]
+    }"#;
+
+    #[test]
+    pub fn config_should_create_generator_and_verifier() {
+        // Given
+        let config: Config = serde_json::from_str(CONFIG).unwrap();
+
+        // When
+        let generator = Generator::from_config(TestHasher::default(), &config);
+        let verifier = Verifier::from_config(TestHasher::default(), &config);
+
+        // Then
+        let key = generator.generate(12345);
+        assert_eq!(create_generator().generate(12345), key);
+        assert_eq!(Status::Valid, verifier.verify(&key));
+        assert_eq!(create_verifier().checks().len(), verifier.checks().len());
+    }
+
+    #[test]
+    pub fn verifier_should_be_created_from_checks_only() {
+        // Given
+        let config: Config = serde_json::from_str(
+            r#"{
+                "checks": [
+                    { "ordinal": 0, "a": 114, "b": 83, "c": 170 },
+                    { "ordinal": 2, "a": 69, "b": 14, "c": 202 }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        // When
+        let verifier = Verifier::from_config(TestHasher::default(), &config);
+
+        // Then
+        let key = create_generator().generate(12345);
+        assert_eq!(Status::Valid, verifier.verify(&key));
+        assert!(config.iv.is_empty());
+        assert!(!serde_json::to_string(&config).unwrap().contains("\"iv\""));
+    }
+
+    #[test]
+    pub fn verifier_should_not_keep_iv_of_config() {
+        // Given
+        let mut config: Config = serde_json::from_str(CONFIG).unwrap();
+        config.checks.push(ByteCheck::new(1, (1, 2, 3)));
+
+        // When
+        let verifier = Verifier::from_config(TestHasher::default(), &config);
+
+        // Then
+        assert_eq!(Ok(()), verifier.validate_checks_against_iv());
+    }
+
+    #[test]
+    pub fn config_should_round_trip_through_json() {
+        // Given
+        let config: Config = serde_json::from_str(CONFIG).unwrap();
+
+        // When
+        let json = serde_json::to_string(&config).unwrap();
+        let result: Config = serde_json::from_str(&json).unwrap();
+
+        // Then
+        assert_eq!(config.iv, result.iv);
+        assert_eq!(2, result.checks.len());
+        assert!(!result.checks[1].decoy);
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// The reflected CRC-32 polynomial
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// Calculates the CRC32 of the provided bytes.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFF_u32;
+    for byte in bytes.iter() {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Combines the CRC32 of two consecutive blocks of bytes into the CRC32
+/// of both blocks, where `second_length` is the length of the second block.
+///
+/// This makes it possible to calculate the CRC32 of different parts of
+/// a large buffer independently and merge the results afterwards.
+pub fn crc32_combine(first: u32, second: u32, second_length: u64) -> u32 {
+    if second_length == 0 {
+        return first;
+    }
+
+    // Operator for a single zero bit
+    let mut odd = [0_u32; 32];
+    odd[0] = POLYNOMIAL;
+    for (n, row) in odd.iter_mut().enumerate().skip(1) {
+        *row = 1 << (n - 1);
+    }
+
+    // Operators for two and four zero bits
+    let mut even = [0_u32; 32];
+    gf2_matrix_square(&mut even, &odd);
+    gf2_matrix_square(&mut odd, &even);
+
+    // Apply one zero byte operator per set bit in the length
+    let mut crc = first;
+    let mut length = second_length;
+    loop {
+        gf2_matrix_square(&mut even, &odd);
+        if length & 1 != 0 {
+            crc = gf2_matrix_times(&even, crc);
+        }
+        length >>= 1;
+        if length == 0 {
+            break;
+        }
+
+        gf2_matrix_square(&mut odd, &even);
+        if length & 1 != 0 {
+            crc = gf2_matrix_times(&odd, crc);
+        }
+        length >>= 1;
+        if length == 0 {
+            break;
+        }
+    }
+
+    crc ^ second
+}
+
+/// Calculates the CRC32 of the provided bytes by splitting them into
+/// chunks of `chunk_size` bytes that are processed in parallel.
+///
+/// The result is the same as for [`crc32`].
+///
+/// [`crc32`]: fn.crc32.html
+#[cfg(feature = "rayon")]
+pub fn crc32_parallel(bytes: &[u8], chunk_size: usize) -> u32 {
+    let (crc, _) = bytes
+        .par_chunks(chunk_size.max(1))
+        .map(|chunk| (crc32(chunk), chunk.len() as u64))
+        .reduce(
+            || (0, 0),
+            |(first, first_length), (second, second_length)| {
+                let crc = crc32_combine(first, second, second_length);
+                (crc, first_length + second_length)
+            },
+        );
+    crc
+}
+
+fn gf2_matrix_times(matrix: &[u32; 32], mut vector: u32) -> u32 {
+    let mut sum = 0;
+    let mut index = 0;
+    while vector != 0 {
+        if vector & 1 != 0 {
+            sum ^= matrix[index];
+        }
+        vector >>= 1;
+        index += 1;
+    }
+    sum
+}
+
+fn gf2_matrix_square(square: &mut [u32; 32], matrix: &[u32; 32]) {
+    for (n, row) in square.iter_mut().enumerate() {
+        *row = gf2_matrix_times(matrix, matrix[n]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_buffer(length: usize) -> Vec<u8> {
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        (0..length)
+            .map(|_| {
+                // Xorshift
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn crc32_should_match_reference_value() {
+        // Given, When
+        let result = crc32(b"123456789");
+
+        // Then
+        assert_eq!(0xCBF4_3926, result);
+    }
+
+    #[test]
+    pub fn combined_crc32_should_equal_serial_crc32() {
+        // Given
+        let buffer = create_buffer(10_000);
+        let (first, second) = buffer.split_at(3_333);
+
+        // When
+        let result = crc32_combine(crc32(first), crc32(second), second.len() as u64);
+
+        // Then
+        assert_eq!(crc32(&buffer), result);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    pub fn parallel_crc32_should_equal_serial_crc32() {
+        // Given
+        let buffer = create_buffer(4 * 1024 * 1024 + 17);
+
+        // When
+        let result = crc32_parallel(&buffer, 64 * 1024);
+
+        // Then
+        assert_eq!(crc32(&buffer), result);
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{crc32, DeserializeError, LicenseKey, Serializer};
+
+const GROUP_SEPARATOR: char = '-';
+
+/// License key serializer that appends a short check group, derived from
+/// a CRC32 of the license key, to the output of another [`Serializer`].
+///
+/// The check group guards against transcription errors, such as a
+/// mistyped character, and is validated before the license key is
+/// handed to the verifier. It's separate from the internal checksum.
+/// If the check group doesn't match, [`DeserializeError::CheckGroupMismatch`]
+/// is returned.
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// # let generator = Generator::new(DummyHasher {}, vec![(1, 2, 3)]);
+/// let key = generator.generate(1234567891011121314_u64);
+/// let format = CrcGroupFormat::new(HexFormat);
+/// let serialized = key.serialize(&format);
+/// let parsed = LicenseKey::parse(&serialized, &format).unwrap();
+/// assert_eq!(key.get_bytes(), parsed.get_bytes());
+/// ```
+///
+/// [`Serializer`]: trait.Serializer.html
+/// [`DeserializeError::CheckGroupMismatch`]: enum.DeserializeError.html#variant.CheckGroupMismatch
+pub struct CrcGroupFormat<S: Serializer> {
+    inner: S,
+}
+
+impl<S: Serializer> CrcGroupFormat<S> {
+    /// Creates a new check group format on top of the specified [`Serializer`].
+    ///
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S: Serializer> Serializer for CrcGroupFormat<S> {
+    fn case_insensitive(&self) -> bool {
+        self.inner.case_insensitive()
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        let group = check_group(key.as_bytes());
+        format!("{}{}{}", self.inner.serialize(key), GROUP_SEPARATOR, group)
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        let (main, group) = match input.rfind(GROUP_SEPARATOR) {
+            Some(index) => (&input[..index], &input[index + 1..]),
+            None => return Err(DeserializeError::MissingCheckGroup),
+        };
+
+        let bytes = self.inner.deserialize(main)?;
+        if !check_group(&bytes).eq_ignore_ascii_case(group) {
+            return Err(DeserializeError::CheckGroupMismatch);
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn check_group(bytes: &[u8]) -> String {
+    // Use the upper 16 bits of the CRC32
+    format!("{:04X}", crc32(bytes) >> 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_key;
+    use crate::HexFormat;
+
+    #[test]
+    pub fn key_with_correct_check_group_should_deserialize() {
+        // Given
+        let key = generate_key(12345);
+        let serialized = key.serialize(&CrcGroupFormat::new(HexFormat));
+
+        // When
+        let result = CrcGroupFormat::new(HexFormat).deserialize(&serialized);
+
+        // Then
+        assert_eq!(Ok(key.get_bytes()), result);
+    }
+
+    #[test]
+    pub fn mistyped_character_should_be_caught_by_check_group() {
+        // Given
+        let key = generate_key(12345);
+        let mut serialized = key.serialize(&CrcGroupFormat::new(HexFormat));
+        let mistyped = if serialized.starts_with('0') {
+            "1"
+        } else {
+            "0"
+        };
+        serialized.replace_range(0..1, mistyped);
+
+        // When
+        let result = CrcGroupFormat::new(HexFormat).deserialize(&serialized);
+
+        // Then
+        assert_eq!(Err(DeserializeError::CheckGroupMismatch), result);
+    }
+}
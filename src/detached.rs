@@ -0,0 +1,124 @@
+use core::convert::TryInto;
+
+use ed25519_dalek::{Signature, VerifyingKey, PUBLIC_KEY_LENGTH};
+
+use crate::{BlockList, KeyHasher, LicenseKey, Status, Verifier};
+
+impl<T: KeyHasher, B: BlockList> Verifier<T, B> {
+    /// Perform verification on the provided license key and a detached
+    /// Ed25519 signature over the license key bytes.
+    ///
+    /// If the signature or the public key isn't valid,
+    /// the license key is considered forged.
+    pub fn verify_with_signature(
+        &self,
+        key: &LicenseKey,
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Status {
+        let status = self.verify(key);
+        if !status.is_valid() {
+            return status;
+        }
+
+        if !verify_signature(&key.bytes, signature, public_key) {
+            return Status::Forged;
+        }
+
+        status
+    }
+}
+
+pub(crate) fn verify_signature(message: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+    let public_key: &[u8; PUBLIC_KEY_LENGTH] = match public_key.try_into() {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let public_key = match VerifyingKey::from_bytes(public_key) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match Signature::from_slice(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    public_key.verify_strict(message, &signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_verifier, generate_key};
+    use crate::{calculate_checksum, KeyLayout, CHECKSUM_BYTE_LENGTH};
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn create_signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    pub fn key_with_valid_signature_should_be_valid() {
+        // Given
+        let key = generate_key(12345);
+        let signing_key = create_signing_key(1);
+        let signature = signing_key.sign(&key.get_bytes());
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_signature(
+            &key,
+            &signature.to_bytes(),
+            signing_key.verifying_key().as_bytes(),
+        );
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn tampered_key_should_be_forged() {
+        // Given
+        let key = generate_key(12345);
+        let signing_key = create_signing_key(1);
+        let signature = signing_key.sign(&key.get_bytes());
+        let verifier = create_verifier();
+
+        // Tamper with a byte that isn't checked and fix the checksum
+        let mut bytes = key.get_bytes();
+        bytes[9] ^= 0xFF;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let tampered = LicenseKey::new(bytes, KeyLayout::default());
+
+        // When
+        let result = verifier.verify_with_signature(
+            &tampered,
+            &signature.to_bytes(),
+            signing_key.verifying_key().as_bytes(),
+        );
+
+        // Then
+        assert_eq!(Status::Valid, verifier.verify(&tampered));
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn wrong_public_key_should_be_forged() {
+        // Given
+        let key = generate_key(12345);
+        let signature = create_signing_key(1).sign(&key.get_bytes());
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_signature(
+            &key,
+            &signature.to_bytes(),
+            create_signing_key(2).verifying_key().as_bytes(),
+        );
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+}
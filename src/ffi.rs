@@ -0,0 +1,315 @@
+/*!
+A C API for verifying license keys from programs that aren't written in Rust.
+
+The API is enabled by the `cffi` feature. Build it as a dynamic or static
+library with `cargo rustc --release --features cffi --crate-type cdylib`
+(or `staticlib`), and declare the functions in C like this:
+
+```c
+typedef struct LicenseKeyVerifier LicenseKeyVerifier;
+
+LicenseKeyVerifier *license_key_verifier_new(const char *config);
+int license_key_verify(const LicenseKeyVerifier *verifier, const char *key);
+int license_key_verify_at(const LicenseKeyVerifier *verifier, const char *key, uint64_t now);
+int license_key_status_is_valid(int status);
+void license_key_verifier_free(LicenseKeyVerifier *verifier);
+```
+
+# Status codes
+
+The verify functions return the exit code of the [`Status`], which is `0`
+for a valid license key and `9` for a trial license key that hasn't expired.
+Both are usable, so check the result with [`license_key_status_is_valid`]
+instead of comparing it with `0`.
+
+Trial and expiring license keys are only accepted by
+[`license_key_verify_at`], which verifies at the specified time (as a Unix
+timestamp, such as `time(NULL)`). Since [`license_key_verify`] doesn't know
+the time, it returns `5` (expired) for every trial license key.
+
+# Configuration
+
+A verifier is created from a configuration string with one directive per
+line. Empty lines and lines starting with `#` are ignored. License keys are
+hashed with an [`HmacHasher`] using the secret.
+
+```text
+secret 736563726574
+check 0 114 83 170
+check 2 69 14 202
+block 1234567891011121314
+layout expiry flags
+```
+
+* `secret <hex>` sets the secret of the hasher. It's required.
+* `check <ordinal> <a> <b> <c>` adds a byte check.
+* `decoy <ordinal> <a> <b> <c>` adds a byte check for a decoy byte.
+* `block <seed>` blocks a seed.
+* `layout <field>...` sets the optional fields of the license keys, such as
+  `expiry` and `flags` for trial license keys. The fields are `seed_checksum`,
+  `version`, `flags`, `device_index`, `region`, `expiry`, `issue_date`,
+  `features` and `long_checksum`.
+
+# Memory ownership
+
+The verifier returned by [`license_key_verifier_new`] is owned by the caller,
+and must be released with [`license_key_verifier_free`] exactly once. Strings
+that are passed to the functions are borrowed for the duration of the call
+and are never released by the library.
+
+[`HmacHasher`]: ../struct.HmacHasher.html
+[`Status`]: ../enum.Status.html
+[`license_key_status_is_valid`]: fn.license_key_status_is_valid.html
+[`license_key_verify_at`]: fn.license_key_verify_at.html
+[`license_key_verify`]: fn.license_key_verify.html
+[`license_key_verifier_new`]: fn.license_key_verifier_new.html
+[`license_key_verifier_free`]: fn.license_key_verifier_free.html
+*/
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+use crate::verifier_config::{parse_config, ConfiguredVerifier};
+use crate::Status;
+
+/// The status code that is returned when a function is called with a null verifier.
+pub const LICENSE_KEY_NULL_VERIFIER: c_int = -1;
+
+/// An opaque license key verifier that is created by [`license_key_verifier_new`].
+///
+/// [`license_key_verifier_new`]: fn.license_key_verifier_new.html
+pub struct LicenseKeyVerifier {
+    configured: ConfiguredVerifier,
+}
+
+/// Creates a new verifier from the specified null-terminated configuration.
+///
+/// Returns a null pointer if the configuration isn't valid. The returned
+/// verifier must be released with [`license_key_verifier_free`].
+///
+/// # Safety
+///
+/// `config` must be null or point to a valid null-terminated string.
+///
+/// [`license_key_verifier_free`]: fn.license_key_verifier_free.html
+#[no_mangle]
+pub unsafe extern "C" fn license_key_verifier_new(
+    config: *const c_char,
+) -> *mut LicenseKeyVerifier {
+    let config = match to_str(config) {
+        Some(config) => config,
+        None => return std::ptr::null_mut(),
+    };
+    match parse_config(config) {
+        Some(configured) => Box::into_raw(Box::new(LicenseKeyVerifier { configured })),
+        None => std::ptr::null_mut(),
+    }
+}
+
+/// Verifies the specified null-terminated hex license key.
+///
+/// Returns the exit code of the [`Status`], which is `0` for a valid license
+/// key. A license key that can't be parsed is invalid. If the verifier is
+/// null, [`LICENSE_KEY_NULL_VERIFIER`] is returned.
+///
+/// The current time is unknown, so trial license keys are expired. Use
+/// [`license_key_verify_at`] to verify trial and expiring license keys.
+///
+/// # Safety
+///
+/// `verifier` must be null or a verifier returned by
+/// [`license_key_verifier_new`] that hasn't been released, and `key`
+/// must be null or point to a valid null-terminated string.
+///
+/// [`Status`]: ../enum.Status.html
+/// [`LICENSE_KEY_NULL_VERIFIER`]: constant.LICENSE_KEY_NULL_VERIFIER.html
+/// [`license_key_verify_at`]: fn.license_key_verify_at.html
+/// [`license_key_verifier_new`]: fn.license_key_verifier_new.html
+#[no_mangle]
+pub unsafe extern "C" fn license_key_verify(
+    verifier: *const LicenseKeyVerifier,
+    key: *const c_char,
+) -> c_int {
+    verify(verifier, key, None)
+}
+
+/// Verifies the specified null-terminated hex license key at the specified
+/// time (as a Unix timestamp), which makes it possible to verify trial and
+/// expiring license keys. See [`license_key_verify`].
+///
+/// # Safety
+///
+/// Same as for [`license_key_verify`].
+///
+/// [`license_key_verify`]: fn.license_key_verify.html
+#[no_mangle]
+pub unsafe extern "C" fn license_key_verify_at(
+    verifier: *const LicenseKeyVerifier,
+    key: *const c_char,
+    now: u64,
+) -> c_int {
+    verify(verifier, key, Some(now))
+}
+
+/// Checks whether or not the status code returned by [`license_key_verify`]
+/// or [`license_key_verify_at`] means that the license key can be used,
+/// which is the case for valid license keys and trials that haven't expired.
+///
+/// Returns `1` if the license key can be used and `0` otherwise.
+///
+/// [`license_key_verify`]: fn.license_key_verify.html
+/// [`license_key_verify_at`]: fn.license_key_verify_at.html
+#[no_mangle]
+pub extern "C" fn license_key_status_is_valid(status: c_int) -> c_int {
+    let valid = Status::Valid.exit_code();
+    let trial = Status::Trial { days_remaining: 0 }.exit_code();
+    (status == valid || status == trial) as c_int
+}
+
+unsafe fn verify(
+    verifier: *const LicenseKeyVerifier,
+    key: *const c_char,
+    now: Option<u64>,
+) -> c_int {
+    let configured = match verifier.as_ref() {
+        Some(verifier) => &verifier.configured,
+        None => return LICENSE_KEY_NULL_VERIFIER,
+    };
+    let status = match to_str(key) {
+        Some(key) => configured.verify(key, now),
+        None => Status::Invalid,
+    };
+    status.exit_code()
+}
+
+/// Releases a verifier that was created by [`license_key_verifier_new`].
+/// Passing a null pointer does nothing.
+///
+/// # Safety
+///
+/// `verifier` must be null or a verifier returned by
+/// [`license_key_verifier_new`] that hasn't been released.
+///
+/// [`license_key_verifier_new`]: fn.license_key_verifier_new.html
+#[no_mangle]
+pub unsafe extern "C" fn license_key_verifier_free(verifier: *mut LicenseKeyVerifier) {
+    if !verifier.is_null() {
+        drop(Box::from_raw(verifier));
+    }
+}
+
+unsafe fn to_str<'a>(input: *const c_char) -> Option<&'a str> {
+    if input.is_null() {
+        return None;
+    }
+    CStr::from_ptr(input).to_str().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Generator, HexFormat, HmacHasher};
+    use std::ffi::CString;
+
+    const CONFIG: &str = "
+        # The secret is 'secret'
+        secret 736563726574
+        check 0 114 83 170
+        check 2 69 14 202
+        block 67890
+    ";
+
+    fn generate_key(seed: u64) -> CString {
+        let generator = Generator::new(
+            HmacHasher::new(b"secret"),
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202)],
+        );
+        CString::new(generator.generate(seed).serialize(&HexFormat)).unwrap()
+    }
+
+    #[test]
+    pub fn verifier_created_from_config_should_verify_keys() {
+        // Given
+        let config = CString::new(CONFIG).unwrap();
+        let garbage = CString::new("not a key").unwrap();
+
+        unsafe {
+            // When
+            let verifier = license_key_verifier_new(config.as_ptr());
+            let valid = license_key_verify(verifier, generate_key(12345).as_ptr());
+            let blocked = license_key_verify(verifier, generate_key(67890).as_ptr());
+            let invalid = license_key_verify(verifier, garbage.as_ptr());
+            let null_key = license_key_verify(verifier, std::ptr::null());
+            license_key_verifier_free(verifier);
+
+            // Then
+            assert_eq!(Status::Valid.exit_code(), valid);
+            assert_eq!(Status::Blocked.exit_code(), blocked);
+            assert_eq!(Status::Invalid.exit_code(), invalid);
+            assert_eq!(Status::Invalid.exit_code(), null_key);
+        }
+    }
+
+    #[test]
+    pub fn invalid_config_should_return_null() {
+        for config in ["check 0 1 2 3", "secret xyz", "check 0 1 2", "unknown"].iter() {
+            // Given
+            let config = CString::new(*config).unwrap();
+
+            // When
+            let verifier = unsafe { license_key_verifier_new(config.as_ptr()) };
+
+            // Then
+            assert!(verifier.is_null());
+        }
+    }
+
+    #[test]
+    pub fn null_verifier_should_be_rejected() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = unsafe { license_key_verify(std::ptr::null(), key.as_ptr()) };
+        unsafe { license_key_verifier_free(std::ptr::null_mut()) };
+
+        // Then
+        assert_eq!(LICENSE_KEY_NULL_VERIFIER, result);
+    }
+
+    #[test]
+    pub fn trial_key_should_only_be_usable_when_verified_at_time() {
+        // Given
+        let config = CString::new(format!("{}\nlayout expiry flags", CONFIG)).unwrap();
+        let mut generator = Generator::new(
+            HmacHasher::new(b"secret"),
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202)],
+        );
+        generator.include_flags(true);
+        let trial = generator.generate_trial(12345, 1_800_000_000);
+        let trial = CString::new(trial.serialize(&HexFormat)).unwrap();
+
+        unsafe {
+            // When
+            let verifier = license_key_verifier_new(config.as_ptr());
+            let active = license_key_verify_at(verifier, trial.as_ptr(), 1_700_000_000);
+            let expired = license_key_verify_at(verifier, trial.as_ptr(), 1_800_000_001);
+            let unknown_time = license_key_verify(verifier, trial.as_ptr());
+            license_key_verifier_free(verifier);
+
+            // Then
+            assert_eq!(Status::Trial { days_remaining: 0 }.exit_code(), active);
+            assert_eq!(1, license_key_status_is_valid(active));
+            assert_eq!(Status::Expired.exit_code(), expired);
+            assert_eq!(0, license_key_status_is_valid(expired));
+            assert_eq!(Status::Expired.exit_code(), unknown_time);
+        }
+    }
+
+    #[test]
+    pub fn only_usable_status_codes_should_be_valid() {
+        assert_eq!(1, license_key_status_is_valid(Status::Valid.exit_code()));
+        assert_eq!(0, license_key_status_is_valid(Status::Forged.exit_code()));
+        assert_eq!(0, license_key_status_is_valid(LICENSE_KEY_NULL_VERIFIER));
+    }
+}
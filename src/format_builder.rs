@@ -0,0 +1,218 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, Grouped, LicenseKey, Serializer};
+
+const STAGE_SEPARATOR: char = '-';
+
+struct Prefixed {
+    inner: Box<dyn Serializer>,
+    prefix: String,
+}
+
+impl Serializer for Prefixed {
+    fn case_insensitive(&self) -> bool {
+        // Prefixes are matched exactly
+        false
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        format!(
+            "{}{}{}",
+            self.prefix,
+            STAGE_SEPARATOR,
+            self.inner.serialize(key)
+        )
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        // A grouped stage on top removes the dash after the prefix
+        match input.strip_prefix(self.prefix.as_str()) {
+            Some(input) => {
+                let input = input.strip_prefix(STAGE_SEPARATOR).unwrap_or(input);
+                self.inner.deserialize(input)
+            }
+            None => Err(DeserializeError::MissingPrefix),
+        }
+    }
+}
+
+/// A builder that composes a license key format by stacking
+/// stages on top of a base [`Serializer`].
+///
+/// When serializing, the stages are applied in the order that they were
+/// added, and when deserializing they are reversed in the opposite order.
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// # let generator = Generator::new(DummyHasher {}, vec![(1, 2, 3)]);
+/// let format = FormatBuilder::base(HexFormat)
+///     .grouped(5)
+///     .prefixed("ACME")
+///     .build();
+///
+/// let key = generator.generate(1234567891011121314_u64);
+/// let serialized = format.serialize(&key);
+/// assert!(serialized.starts_with("ACME-"));
+/// assert_eq!(Ok(key.get_bytes()), format.deserialize(&serialized));
+/// ```
+///
+/// [`Serializer`]: trait.Serializer.html
+pub struct FormatBuilder {
+    format: Box<dyn Serializer>,
+}
+
+impl FormatBuilder {
+    /// Creates a new builder on top of the specified [`Serializer`].
+    ///
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn base(serializer: impl Serializer + 'static) -> Self {
+        Self {
+            format: Box::new(serializer),
+        }
+    }
+
+    /// Splits the serialized license key into dash-separated
+    /// groups of the specified number of characters.
+    ///
+    /// Just like with [`Grouped`], missing or extra dashes
+    /// are tolerated when deserializing.
+    ///
+    /// [`Grouped`]: struct.Grouped.html
+    pub fn grouped(self, size: usize) -> Self {
+        Self {
+            format: Box::new(Grouped::new(self.format, size, STAGE_SEPARATOR)),
+        }
+    }
+
+    /// Adds the specified prefix, followed by a dash,
+    /// in front of the serialized license key.
+    pub fn prefixed(self, prefix: &str) -> Self {
+        Self {
+            format: Box::new(Prefixed {
+                inner: self.format,
+                prefix: prefix.to_string(),
+            }),
+        }
+    }
+
+    /// Builds the composed format.
+    pub fn build(self) -> ComposedFormat {
+        ComposedFormat {
+            format: self.format,
+        }
+    }
+}
+
+/// A license key format built by a [`FormatBuilder`].
+///
+/// [`FormatBuilder`]: struct.FormatBuilder.html
+pub struct ComposedFormat {
+    format: Box<dyn Serializer>,
+}
+
+impl Serializer for ComposedFormat {
+    fn case_insensitive(&self) -> bool {
+        self.format.case_insensitive()
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        self.format.serialize(key)
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        self.format.deserialize(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_key;
+    use crate::HexFormat;
+
+    #[test]
+    pub fn built_format_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+        let format = FormatBuilder::base(HexFormat)
+            .grouped(5)
+            .prefixed("ACME")
+            .build();
+
+        // When
+        let result = format.deserialize(&format.serialize(&key));
+
+        // Then
+        assert_eq!(Ok(key.get_bytes()), result);
+    }
+
+    #[test]
+    pub fn stages_should_apply_in_order() {
+        // Given
+        let key = generate_key(12345);
+        let hex = key.serialize(&HexFormat);
+        let grouped_then_prefixed = FormatBuilder::base(HexFormat)
+            .grouped(5)
+            .prefixed("ACME")
+            .build();
+        let prefixed_then_grouped = FormatBuilder::base(HexFormat)
+            .prefixed("ACME")
+            .grouped(5)
+            .build();
+
+        // When
+        let first = grouped_then_prefixed.serialize(&key);
+        let second = prefixed_then_grouped.serialize(&key);
+
+        // Then
+        assert!(first.starts_with(&format!("ACME-{}-{}-", &hex[..5], &hex[5..10])));
+        assert!(second.starts_with(&format!("ACME--{}-", &hex[..5])));
+        assert_eq!(
+            Ok(key.get_bytes()),
+            prefixed_then_grouped.deserialize(&second)
+        );
+    }
+
+    #[test]
+    pub fn missing_prefix_should_not_deserialize() {
+        // Given
+        let key = generate_key(12345);
+        let format = FormatBuilder::base(HexFormat).prefixed("ACME").build();
+
+        // When
+        let result = format.deserialize(&key.serialize(&HexFormat));
+
+        // Then
+        assert_eq!(Err(DeserializeError::MissingPrefix), result);
+    }
+
+    #[test]
+    pub fn grouped_stage_should_tolerate_missing_and_extra_separators() {
+        // Given
+        let key = generate_key(12345);
+        let hex = key.serialize(&HexFormat);
+        let format = FormatBuilder::base(HexFormat)
+            .grouped(5)
+            .prefixed("ACME")
+            .build();
+        let missing = format!("ACME-{}", hex);
+        let extra = format!("ACME-{}--{}-", &hex[..3], &hex[3..]);
+
+        // When
+        let missing = format.deserialize(&missing);
+        let extra = format.deserialize(&extra);
+
+        // Then
+        assert_eq!(Ok(key.get_bytes()), missing);
+        assert_eq!(Ok(key.get_bytes()), extra);
+    }
+}
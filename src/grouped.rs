@@ -0,0 +1,122 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, LicenseKey, Serializer};
+
+/// License key serializer that splits the output of another
+/// [`Serializer`] into groups of characters separated by a separator.
+///
+/// When deserializing, all separators are removed before the input is
+/// passed on, which means that missing or extra separators are tolerated.
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// # let generator = Generator::new(DummyHasher {}, vec![(1, 2, 3)]);
+/// let key = generator.generate(1234567891011121314_u64);
+///
+/// // Outputs something like 112210f4b2-d230a22955-2341e723
+/// let format = Grouped::new(HexFormat, 10, '-');
+/// let serialized = key.serialize(&format);
+/// let parsed = LicenseKey::parse(&serialized, &format).unwrap();
+/// assert_eq!(key.get_bytes(), parsed.get_bytes());
+/// ```
+///
+/// [`Serializer`]: trait.Serializer.html
+pub struct Grouped<S: Serializer> {
+    inner: S,
+    size: usize,
+    separator: char,
+}
+
+impl<S: Serializer> Grouped<S> {
+    /// Creates a new grouped format on top of the specified [`Serializer`],
+    /// with groups of `size` characters separated by `separator`.
+    ///
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn new(inner: S, size: usize, separator: char) -> Self {
+        Self {
+            inner,
+            size: size.max(1),
+            separator,
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for Grouped<S> {
+    fn case_insensitive(&self) -> bool {
+        self.inner.case_insensitive()
+    }
+
+    fn serialize(&self, key: &LicenseKey) -> String {
+        let mut output = String::new();
+        for (index, character) in self.inner.serialize(key).chars().enumerate() {
+            if index > 0 && index % self.size == 0 {
+                output.push(self.separator);
+            }
+            output.push(character);
+        }
+        output
+    }
+
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        let input: String = input.chars().filter(|c| *c != self.separator).collect();
+        self.inner.deserialize(&input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::generate_key;
+    use crate::HexFormat;
+
+    #[test]
+    pub fn grouped_should_insert_separators() {
+        // Given
+        let key = generate_key(12345);
+        let hex = key.serialize(&HexFormat);
+
+        // When
+        let result = key.serialize(&Grouped::new(HexFormat, 4, ' '));
+
+        // Then
+        assert_eq!(format!("{} {} ", &hex[..4], &hex[4..8]), result[..10]);
+        assert_eq!(hex.len() + (hex.len() - 1) / 4, result.len());
+    }
+
+    #[test]
+    pub fn grouped_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+        let format = Grouped::new(HexFormat, 5, '-');
+
+        // When
+        let result = LicenseKey::parse(&key.serialize(&format), &format);
+
+        // Then
+        assert_eq!(key.get_bytes(), result.unwrap().get_bytes());
+    }
+
+    #[test]
+    pub fn grouped_should_tolerate_missing_and_extra_separators() {
+        // Given
+        let key = generate_key(12345);
+        let hex = key.serialize(&HexFormat);
+        let pasted = format!("-{}--{}-", &hex[..3], &hex[3..]);
+        let format = Grouped::new(HexFormat, 5, '-');
+
+        // When
+        let missing = format.deserialize(&hex);
+        let extra = format.deserialize(&pasted);
+
+        // Then
+        assert_eq!(Ok(key.get_bytes()), missing);
+        assert_eq!(Ok(key.get_bytes()), extra);
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::KeyHasher;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// A [`KeyHasher`] that hashes the seed and the initialization
+/// vector values using HMAC-SHA256 and a secret key.
+///
+/// The hashed byte is the lowest byte of the HMAC output. Unlike
+/// a hand-rolled hasher, the payload can't be calculated without
+/// knowing the secret key, so keep it out of the verifier if you
+/// ship the verifier to your customers.
+///
+/// ```
+/// use license_key::*;
+/// let hasher = HmacHasher::new(b"secret");
+/// let generator = Generator::new(hasher, vec![(114, 83, 170), (60, 208, 27)]);
+/// let key = generator.generate(1234567891011121314_u64);
+///
+/// let verifier = Verifier::new(
+///     HmacHasher::new(b"secret"),
+///     vec![ByteCheck::new(0, (114, 83, 170))],
+/// );
+/// assert_eq!(Status::Valid, verifier.verify(&key));
+/// ```
+///
+/// [`KeyHasher`]: trait.KeyHasher.html
+pub struct HmacHasher {
+    secret: Vec<u8>,
+}
+
+impl HmacHasher {
+    /// Creates a new HMAC-SHA256 hasher with the specified secret key.
+    pub fn new(secret_key: &[u8]) -> Self {
+        Self {
+            secret: secret_key.to_vec(),
+        }
+    }
+}
+
+impl HmacHasher {
+    fn mac(&self, seed: u64, a: u64, b: u64, c: u64) -> [u8; 32] {
+        let mut mac = HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts any key size");
+        for value in [seed, a, b, c].iter() {
+            mac.update(&value.to_be_bytes());
+        }
+        mac.finalize().into_bytes().into()
+    }
+}
+
+impl KeyHasher for HmacHasher {
+    fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+        let result = self.mac(seed, a, b, c);
+        result[result.len() - 1]
+    }
+
+    fn hash_segment(&self, seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+        // Use the bytes from the end of the MAC, so that the first
+        // byte of a segment is the same as the output of `hash`.
+        let result = self.mac(seed, a, b, c);
+        for (byte, value) in segment.iter_mut().zip(result.iter().rev()) {
+            *byte = *value;
+        }
+    }
+
+    fn fold_seed(&self, seed: &[u8]) -> u64 {
+        // The other MACs always cover 32 bytes, and seeds that
+        // are folded are never 64 bits long, so they can't collide.
+        let mut mac = HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts any key size");
+        mac.update(seed);
+        let result = mac.finalize().into_bytes();
+        u64::from_be_bytes(result[..8].try_into().unwrap())
+    }
+}
+
+/// Wipes the secret key of the hasher.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for HmacHasher {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Wipes the secret key of the hasher when it's dropped.
+#[cfg(feature = "zeroize")]
+impl Drop for HmacHasher {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for HmacHasher {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn hmac_hasher_should_match_test_vector() {
+        // Given
+        let hasher = HmacHasher::new(b"secret");
+
+        // When
+        let result = hasher.hash(1234567891011121314, 114, 83, 170);
+
+        // Then
+        assert_eq!(33, result);
+    }
+
+    #[test]
+    pub fn hmac_hasher_segment_should_start_with_hash() {
+        // Given
+        let hasher = HmacHasher::new(b"secret");
+        let mut segment = [0; 4];
+
+        // When
+        hasher.hash_segment(1234567891011121314, 114, 83, 170, &mut segment);
+
+        // Then
+        assert_eq!(33, segment[0]);
+        assert_ne!([33, 33, 33, 33], segment);
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    pub fn zeroized_hmac_hasher_should_forget_secret() {
+        // Given
+        use zeroize::Zeroize;
+        let mut hasher = HmacHasher::new(b"secret");
+
+        // When
+        hasher.zeroize();
+
+        // Then
+        assert!(hasher.secret.is_empty());
+        assert_ne!(33, hasher.hash(1234567891011121314, 114, 83, 170));
+    }
+}
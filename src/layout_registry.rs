@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+
+use crate::{DeserializeError, KeyLayout, LicenseKey, Serializer};
+
+/// Maps the versions of license keys to their [`KeyLayout`], which
+/// makes it possible to parse license keys of different versions
+/// without knowing their layout up front.
+///
+/// The version is read from the version byte of every registered layout,
+/// and the license key gets the layout of the first version that matches.
+/// See [`Generator::include_version`].
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// let mut generator = Generator::new(DummyHasher {}, vec![(1, 2, 3), (4, 5, 6)]);
+/// generator.include_version(2);
+/// let input = generator
+///     .generate_with_expiry(12345, 1_800_000_000)
+///     .serialize(&HexFormat);
+///
+/// let mut layouts = LayoutRegistry::new();
+/// layouts.register(1, KeyLayout::default());
+/// layouts.register(2, KeyLayout { expiry: true, ..KeyLayout::default() });
+///
+/// let key = layouts.parse(&input, &HexFormat).unwrap();
+/// assert_eq!(Some(1_800_000_000), key.expires_at());
+/// ```
+///
+/// [`KeyLayout`]: struct.KeyLayout.html
+/// [`Generator::include_version`]: struct.Generator.html#method.include_version
+#[derive(Debug, Default, Clone)]
+pub struct LayoutRegistry {
+    layouts: Vec<(u8, KeyLayout)>,
+}
+
+impl LayoutRegistry {
+    /// Creates a new layout registry without any layouts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the layout of license keys with the specified version,
+    /// replacing any layout previously registered for that version.
+    ///
+    /// The layout always gets the version field, whether or not
+    /// `version` is set in the provided layout.
+    pub fn register(&mut self, version: u8, layout: KeyLayout) {
+        let layout = KeyLayout {
+            version: true,
+            ..layout
+        };
+        match self.layouts.iter_mut().find(|(other, _)| *other == version) {
+            Some(entry) => entry.1 = layout,
+            None => self.layouts.push((version, layout)),
+        }
+    }
+
+    /// Gets the layout registered for the specified version, if any.
+    pub fn layout(&self, version: u8) -> Option<KeyLayout> {
+        self.layouts
+            .iter()
+            .find(|(other, _)| *other == version)
+            .map(|(_, layout)| *layout)
+    }
+
+    /// Gets the layout of the license key with the specified bytes,
+    /// or `None` if the version isn't registered.
+    pub fn detect(&self, bytes: &[u8]) -> Option<KeyLayout> {
+        self.layouts
+            .iter()
+            .find(|(version, layout)| bytes.get(layout.version_offset()) == Some(version))
+            .map(|(_, layout)| *layout)
+    }
+
+    /// Deserializes a [`&str`] into a license key by using the provided
+    /// [`Serializer`], with the layout registered for its version.
+    ///
+    /// If the version of the license key isn't registered,
+    /// [`DeserializeError::UnsupportedVersion`] is returned.
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
+    /// [`Serializer`]: trait.Serializer.html
+    /// [`DeserializeError::UnsupportedVersion`]: enum.DeserializeError.html#variant.UnsupportedVersion
+    pub fn parse<T: Serializer + ?Sized>(
+        &self,
+        input: &str,
+        serializer: &T,
+    ) -> Result<LicenseKey, DeserializeError> {
+        self.create_key(serializer.deserialize(input)?)
+    }
+
+    /// Creates a license key from raw bytes, with the layout
+    /// registered for its version. See [`LayoutRegistry::parse`].
+    ///
+    /// [`LayoutRegistry::parse`]: struct.LayoutRegistry.html#method.parse
+    pub fn parse_bytes(&self, bytes: &[u8]) -> Result<LicenseKey, DeserializeError> {
+        self.create_key(bytes.to_vec())
+    }
+
+    fn create_key(&self, bytes: Vec<u8>) -> Result<LicenseKey, DeserializeError> {
+        if bytes.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+        match self.detect(&bytes) {
+            Some(layout) => LicenseKey::from_parts(bytes, layout),
+            None => Err(DeserializeError::UnsupportedVersion),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier};
+    use crate::{FixedClock, HexFormat, Status};
+
+    fn create_registry() -> LayoutRegistry {
+        let mut layouts = LayoutRegistry::new();
+        layouts.register(1, KeyLayout::default());
+        layouts.register(
+            2,
+            KeyLayout {
+                expiry: true,
+                ..KeyLayout::default()
+            },
+        );
+        layouts
+    }
+
+    #[test]
+    pub fn keys_of_every_version_should_be_verified_by_same_verifier() {
+        // Given
+        let mut v1 = create_generator();
+        v1.include_version(1);
+        let mut v2 = create_generator();
+        v2.include_version(2);
+        let v1 = v1.generate(12345).serialize(&HexFormat);
+        let v2 = v2
+            .generate_with_expiry(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let mut verifier = create_verifier();
+        verifier.expect_payload_length(4);
+        verifier.use_clock(FixedClock(1_700_000_000));
+        verifier.use_layouts(create_registry());
+
+        // When
+        let first = create_registry().parse(&v1, &HexFormat).unwrap();
+        let second = create_registry().parse(&v2, &HexFormat).unwrap();
+
+        // Then
+        assert_eq!(None, first.expires_at());
+        assert_eq!(Some(1_800_000_000), second.expires_at());
+        assert_eq!(Status::Valid, verifier.verify(&first));
+        assert_eq!(Status::Valid, verifier.verify(&second));
+        assert_eq!(Status::Valid, verifier.verify_str(&v1, &HexFormat));
+        assert_eq!(Status::Valid, verifier.verify_str(&v2, &HexFormat));
+    }
+
+    #[test]
+    pub fn key_with_unregistered_version_should_not_parse() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_version(3);
+        let versioned = generator.generate(12345).serialize(&HexFormat);
+
+        // When
+        let result = create_registry().parse(&versioned, &HexFormat);
+
+        // Then
+        assert_eq!(Err(DeserializeError::UnsupportedVersion), result);
+        assert_eq!(
+            Err(DeserializeError::Empty),
+            create_registry().parse_bytes(&[])
+        );
+    }
+}
@@ -10,6 +10,14 @@ validate the license key over the Internet.
 * Not possible to disassemble an application to gain
   insight into how to generate a 100% working key since 
   the verification process doesn't check the whole license key.
+* Works without the standard library (`no_std`) by disabling
+  the default `std` feature. An allocator is still required.
+* License keys and secrets can be wiped from memory when they're
+  dropped by enabling the `zeroize` feature.
+* License keys can be verified from C and other languages through
+  the C API in the `ffi` module by enabling the `cffi` feature.
+* License keys can be verified in the browser by compiling to
+  WebAssembly with the `wasm` feature. See `WasmVerifier`.
 
 For more information, read [`Implementing a Partial Serial Number Verification System in Delphi`]
 by Brandon Staggs, which this crate was based upon.
@@ -18,9 +26,10 @@ by Brandon Staggs, which this crate was based upon.
 
 Every license key consists of a seed, a payload and a checksum.
 Each byte in the payload is an operation of the seed and an
-initialization vector. The 16-bit checksum is there to quickly check if
-the key is valid at all, while the seed is a 64-bit hash of something
-that identifies the license key owner such as an e-mail address or similar.  
+initialization vector. The 16-bit (or optionally 32-bit) checksum
+is there to quickly check if the key is valid at all, while the seed
+is a 32, 64 (the default) or 128-bit hash of something that
+identifies the license key owner such as an e-mail address or similar.
 
 The size of the payload depends on how big the initialization vector is.
 In the example below, we are using a 5-byte intitialization vector which
@@ -71,7 +80,7 @@ let key = generator.generate(1234567891011121314_u64);
 
 // Write the key in hex format to the console.
 // This will output something like: 112210F4B2D230A229552341B2E723
-println!("{}", key.serialize::<HexFormat>());
+println!("{}", key.serialize(&HexFormat));
 ```
 
 # Verifying a license key
@@ -104,7 +113,7 @@ let mut verifier = Verifier::new(
 verifier.block(11111111_u64);
 
 // Parse a key in hex format
-let key = LicenseKey::parse::<HexFormat>("112210F4B2D230A229552341E723");
+let key = LicenseKey::parse("112210F4B2D230A229552341E723", &HexFormat).unwrap();
 
 // Verify the license key
 match verifier.verify(&key) {
@@ -112,6 +121,7 @@ match verifier.verify(&key) {
     Status::Invalid => println!("Key is invalid!"),
     Status::Blocked => println!("Key has been blocked!"),
     Status::Forged => println!("Key has been forged!"),
+    _ => println!("Key could not be verified!"),
 }
 ```
 
@@ -119,300 +129,6338 @@ match verifier.verify(&key) {
 https://www.brandonstaggs.com/2007/07/26/implementing-a-partial-serial-number-verification-system-in-delphi
 */
 
-use std::convert::TryInto;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+// Without std, the blocklist and other lookups are backed by B-trees.
+#[cfg(not(feature = "std"))]
+pub(crate) use alloc::collections::{BTreeMap as Map, BTreeSet as Set};
+#[cfg(feature = "std")]
+pub(crate) use std::collections::{HashMap as Map, HashSet as Set};
 
-const SEED_BYTE_LENGTH: u8 = 8;
 const CHECKSUM_BYTE_LENGTH: u8 = 2;
-const SEGMENT_BYTE_LENGTH: u8 = 1;
+const LONG_CHECKSUM_BYTE_LENGTH: u8 = 4;
+const SEED_CHECKSUM_BYTE_LENGTH: u8 = 1;
+const VERSION_BYTE_LENGTH: u8 = 1;
+const FLAGS_BYTE_LENGTH: u8 = 1;
+const TRIAL_FLAG: u8 = 1;
+const DEVICE_INDEX_BYTE_LENGTH: u8 = 4;
+const REGION_BYTE_LENGTH: u8 = 2;
+const EXPIRY_BYTE_LENGTH: u8 = 4;
+const ISSUE_DATE_BYTE_LENGTH: u8 = 2;
+const SECONDS_PER_DAY: u32 = 86_400;
+const FEATURES_BYTE_LENGTH: u8 = 4;
+const SEQUENCE_SEED_ROUNDS: u8 = 8;
+
+// The longest license key has a 128-bit seed, every optional field, 256
+// segments of four bytes (the most that byte checks can address) and
+// a long checksum.
+pub(crate) const MAX_KEY_LENGTH: usize = 16
+    + (SEED_CHECKSUM_BYTE_LENGTH
+        + VERSION_BYTE_LENGTH
+        + FLAGS_BYTE_LENGTH
+        + DEVICE_INDEX_BYTE_LENGTH
+        + REGION_BYTE_LENGTH
+        + EXPIRY_BYTE_LENGTH
+        + ISSUE_DATE_BYTE_LENGTH
+        + FEATURES_BYTE_LENGTH
+        + CLAIMS_BYTE_LENGTH
+        + SIGNATURE_BYTE_LENGTH
+        + LONG_CHECKSUM_BYTE_LENGTH) as usize
+    + 256 * 4;
+
+// Initialization vector triplets that have been published as examples
+// in the documentation, examples and benchmarks of this crate.
+const PUBLISHED_IV: &[(u64, u64, u64)] = &[
+    (114, 83, 170),
+    (60, 208, 27),
+    (69, 14, 202),
+    (61, 232, 54),
+    (112344, 812393, 175439050),
+    (64234200, 223423408, 253485347),
+    (653453459, 153454, 2534502),
+    (675331, 23452, 553453454),
+];
+const CLAIMS_BYTE_LENGTH: u8 = 10;
+const SIGNATURE_BYTE_LENGTH: u8 = 16;
+
+mod base32;
+mod base58;
+mod base64;
+mod blocklist;
+mod checksum;
+mod clock;
+#[cfg(feature = "serde")]
+mod config;
+mod crc32;
+mod crc_group;
+#[cfg(feature = "ed25519")]
+mod detached;
+#[cfg(feature = "cffi")]
+pub mod ffi;
+mod format_builder;
+mod grouped;
+mod layout_registry;
+#[cfg(feature = "hmac")]
+mod hmac_hasher;
+mod random;
+mod rate_limit;
+mod reference;
+mod seed;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "hmac")]
+mod signing;
+#[cfg(feature = "ed25519")]
+mod snapshot;
+mod stream;
+mod verifier_builder;
+#[cfg(any(feature = "cffi", feature = "wasm"))]
+mod verifier_config;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use base32::{Base32Format, Rfc4648Base32Format, VoiceFriendlyFormat};
+pub use base58::Base58Format;
+pub use base64::Base64UrlFormat;
+pub use blocklist::{BlockList, MemoryBlockList};
+pub use checksum::{Checksum, Crc16, DefaultChecksum, Fletcher16, SeededChecksum};
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, FixedClock};
+#[cfg(feature = "serde")]
+pub use config::Config;
+#[cfg(feature = "rayon")]
+pub use crc32::crc32_parallel;
+pub use crc32::{crc32, crc32_combine};
+pub use crc_group::CrcGroupFormat;
+pub use format_builder::{ComposedFormat, FormatBuilder};
+pub use grouped::Grouped;
+pub use layout_registry::LayoutRegistry;
+#[cfg(feature = "hmac")]
+pub use hmac_hasher::HmacHasher;
+pub use random::{RandomSource, SeededRandom};
+pub use rate_limit::{AttemptStore, MemoryAttemptStore, RateLimiter, DEFAULT_TRACKED_SEEDS};
+pub use reference::{
+    run_generator_vectors, run_reference_vectors, GeneratorVector, ReferenceHasher,
+    GENERATOR_VECTORS,
+};
+pub use seed::{Seed, SeedLength};
+pub use stream::StreamVerifier;
+pub use verifier_builder::VerifierBuilder;
+
+#[cfg(feature = "std")]
+pub use shared::SharedVerifier;
+#[cfg(feature = "hmac")]
+pub use signing::ClaimSigner;
+#[cfg(feature = "ed25519")]
+pub use snapshot::Snapshot;
+#[cfg(feature = "wasm")]
+pub use wasm::WasmVerifier;
 
 /// Represent a hasher that turns the seed and a part of the
 /// initialization vector into a license key byte.
+///
+/// The trait is object safe, which means that a hasher can be chosen at
+/// runtime by using a `Generator<Box<dyn KeyHasher>>` or a
+/// `Verifier<Box<dyn KeyHasher>>`.
+///
+/// Hashers that produce more than one byte of output can fill a wide
+/// payload segment in a single call by overriding [`KeyHasher::hash_segment`].
+///
+/// ```
+/// use license_key::*;
+/// struct WideHasher {}
+/// impl KeyHasher for WideHasher {
+///     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+///         self.hash_u32(seed, a, b, c) as u8
+///     }
+///
+///     fn hash_segment(&self, seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+///         let bytes = self.hash_u32(seed, a, b, c).to_le_bytes();
+///         segment.copy_from_slice(&bytes[..segment.len()]);
+///     }
+/// }
+/// # impl WideHasher {
+/// #     fn hash_u32(&self, seed: u64, a: u64, b: u64, c: u64) -> u32 {
+/// #         (seed ^ a ^ b ^ c).wrapping_mul(0x9E37_79B9) as u32
+/// #     }
+/// # }
+///
+/// let mut generator = Generator::new(WideHasher {}, vec![(114, 83, 170)]);
+/// generator.use_segment_length(SegmentLength::Four);
+/// let key = generator.generate(1234567891011121314_u64);
+///
+/// let verifier = Verifier::new(WideHasher {}, vec![ByteCheck::new(0, (114, 83, 170))]);
+/// assert_eq!(Status::Valid, verifier.verify(&key));
+/// ```
+///
+/// [`KeyHasher::hash_segment`]: trait.KeyHasher.html#method.hash_segment
 pub trait KeyHasher {
     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8;
-}
 
-/// Represents a license key serializer.
-pub trait Serializer {
-    /// Serializes a license key to a string.
-    fn serialize(key: &LicenseKey) -> String;
+    /// Hashes the seed and an initialization vector triplet into a payload
+    /// segment that is wider than one byte. See [`SegmentLength`].
+    ///
+    /// The default implementation calls [`KeyHasher::hash`] once for every
+    /// byte of the segment, with a different seed for every byte after
+    /// the first one. Hashers that produce more than one byte of output
+    /// can override this to fill the segment in one go.
+    ///
+    /// [`SegmentLength`]: enum.SegmentLength.html
+    /// [`KeyHasher::hash`]: trait.KeyHasher.html#tymethod.hash
+    fn hash_segment(&self, seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+        for (index, byte) in segment.iter_mut().enumerate() {
+            *byte = self.hash(segment_seed(seed, index), a, b, c);
+        }
+    }
 
-    /// Deserializes a license key into a byte vector.
-    fn deserialize(input: &str) -> Vec<u8>;
+    /// Folds a seed that isn't 64 bits long, such as a 128-bit seed,
+    /// into the 64-bit seed that the payload is hashed with. See [`Seed`].
+    ///
+    /// The default implementation calls [`KeyHasher::hash`] once for every
+    /// byte of the folded seed, with both halves of the seed and its length,
+    /// so that related seeds don't share a folded seed. Hashers that produce
+    /// more than one byte of output can override this to fold the seed in
+    /// one go, but must always hash the whole seed.
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`KeyHasher::hash`]: trait.KeyHasher.html#tymethod.hash
+    fn fold_seed(&self, seed: &[u8]) -> u64 {
+        let seed_value = seed
+            .iter()
+            .fold(0_u128, |value, byte| (value << 8) | *byte as u128);
+        let (high, low) = ((seed_value >> 64) as u64, seed_value as u64);
+        let length = seed.len() as u64;
+        (0..8).fold(0, |folded, index| {
+            let mixed = mix_wide_seed(high, low, length, index);
+            let byte = self.hash(mixed, high, low, (length << 8) | index);
+            (folded << 8) | byte as u64
+        })
+    }
 }
 
-/// License key serializer for hex strings.
-pub struct HexFormat {}
-impl Serializer for HexFormat {
-    fn serialize(key: &LicenseKey) -> String {
-        hex::encode(key.get_bytes())
+impl<H: KeyHasher + ?Sized> KeyHasher for Box<H> {
+    fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+        (**self).hash(seed, a, b, c)
     }
 
-    fn deserialize(input: &str) -> Vec<u8> {
-        hex::decode(input).unwrap()
+    fn hash_segment(&self, seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+        (**self).hash_segment(seed, a, b, c, segment)
     }
-}
 
-/// Represents a generated or parsed license key.
-#[derive(Debug, Clone)]
-pub struct LicenseKey {
-    bytes: Vec<u8>,
+    fn fold_seed(&self, seed: &[u8]) -> u64 {
+        (**self).fold_seed(seed)
+    }
 }
 
-impl LicenseKey {
-    pub(crate) fn new(bytes: Vec<u8>) -> Self {
-        Self { bytes }
+/// Represents a license key serializer.
+///
+/// Serializers are instantiated, which makes it possible to configure
+/// a format at runtime. The trait is object safe, so a serializer can
+/// also be chosen at runtime by using a `&dyn Serializer`.
+pub trait Serializer {
+    /// Whether or not the format ignores the case of the input when
+    /// deserializing. Formats whose alphabet allows it should normalize
+    /// the case before decoding, since users tend to mistype case.
+    fn case_insensitive(&self) -> bool {
+        false
     }
 
-    /// Deserializes a [`&str`] into a license key by using the
-    /// provided [`Serializer`].
-    ///
-    /// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
-    /// [`Serializer`]: trait.Serializer.html
-    pub fn parse<T : Serializer>(input: &str) -> LicenseKey {
-        LicenseKey::new(T::deserialize(input))
+    /// Serializes a license key to a string.
+    fn serialize(&self, key: &LicenseKey) -> String;
+
+    /// Deserializes a license key into a byte vector.
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError>;
+}
+
+impl<S: Serializer + ?Sized> Serializer for Box<S> {
+    fn case_insensitive(&self) -> bool {
+        (**self).case_insensitive()
     }
 
-    /// Serializes the license key into a [`String`] by using the 
-    /// provided [`Serializer`].
-    ///
-    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
-    /// [`Serializer`]: trait.Serializer.html
-    pub fn serialize<T: Serializer>(&self) -> String {
-        T::serialize(&self)
+    fn serialize(&self, key: &LicenseKey) -> String {
+        (**self).serialize(key)
     }
 
-    /// Gets the individual bytes that makes up the license key.
-    pub fn get_bytes(&self) -> Vec<u8> {
-        self.bytes.clone()
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        (**self).deserialize(input)
     }
+}
 
-    pub(crate) fn get_byte(&self, ordinal: usize) -> Option<u8> {
-        let index = SEED_BYTE_LENGTH as usize + (ordinal * SEGMENT_BYTE_LENGTH as usize);
-        if index > self.bytes.len() - 3 {
-            return None;
+/// An error that occurs when a license key can't be deserialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeserializeError {
+    /// The input is empty.
+    Empty,
+    /// The input contains a character that isn't part
+    /// of the format, at the specified index.
+    InvalidCharacter { character: char, index: usize },
+    /// The input doesn't contain an even number of hex characters.
+    OddLength,
+    /// The input doesn't contain a check group.
+    /// See [`CrcGroupFormat`].
+    ///
+    /// [`CrcGroupFormat`]: struct.CrcGroupFormat.html
+    MissingCheckGroup,
+    /// The check group doesn't match the license key.
+    /// See [`CrcGroupFormat`].
+    ///
+    /// [`CrcGroupFormat`]: struct.CrcGroupFormat.html
+    CheckGroupMismatch,
+    /// The input doesn't start with the expected prefix.
+    /// See [`FormatBuilder::prefixed`].
+    ///
+    /// [`FormatBuilder::prefixed`]: struct.FormatBuilder.html#method.prefixed
+    MissingPrefix,
+    /// The input is too short to contain the seed, the optional fields
+    /// of the layout and the checksum.
+    TooShort { length: usize, minimum: usize },
+    /// The input is longer than any license key can be.
+    TooLong { length: usize, maximum: usize },
+    /// No layout has been registered for the version of the license key.
+    /// See [`LayoutRegistry`].
+    ///
+    /// [`LayoutRegistry`]: struct.LayoutRegistry.html
+    UnsupportedVersion,
+}
+
+impl core::fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DeserializeError::Empty => write!(f, "the license key is empty"),
+            DeserializeError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character '{}' at index {}", character, index)
+            }
+            DeserializeError::OddLength => write!(f, "the license key has an odd length"),
+            DeserializeError::MissingCheckGroup => write!(f, "the check group is missing"),
+            DeserializeError::CheckGroupMismatch => write!(f, "the check group doesn't match"),
+            DeserializeError::MissingPrefix => write!(f, "the prefix is missing"),
+            DeserializeError::TooShort { length, minimum } => write!(
+                f,
+                "the license key is {} bytes long, but must be at least {} bytes",
+                length, minimum
+            ),
+            DeserializeError::TooLong { length, maximum } => write!(
+                f,
+                "the license key is {} characters long, but must be at most {} characters",
+                length, maximum
+            ),
+            DeserializeError::UnsupportedVersion => {
+                write!(f, "the license key has an unsupported version")
+            }
         }
-        Some(self.bytes[index])
     }
+}
 
-    pub(crate) fn get_checksum(&self) -> &[u8] {
-        &self.bytes[self.bytes.len() - CHECKSUM_BYTE_LENGTH as usize..]
+#[cfg(feature = "std")]
+impl std::error::Error for DeserializeError {}
+
+/// License key serializer for hex strings.
+pub struct HexFormat;
+impl Serializer for HexFormat {
+    fn case_insensitive(&self) -> bool {
+        true
     }
 
-    pub(crate) fn get_seed(&self) -> u64 {
-        u64::from_be_bytes(self.bytes[0..SEED_BYTE_LENGTH as usize].try_into().unwrap())
+    fn serialize(&self, key: &LicenseKey) -> String {
+        hex::encode(key.as_bytes())
     }
 
-    pub(crate) fn calculate_checksum(&self) -> [u8; 2] {
-        calculate_checksum(&self.bytes[0..self.bytes.len() - CHECKSUM_BYTE_LENGTH as usize])
+    fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        if input.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+
+        hex::decode(input.to_ascii_lowercase()).map_err(|err| match err {
+            hex::FromHexError::InvalidHexCharacter { c, index } => {
+                DeserializeError::InvalidCharacter {
+                    character: input[index..].chars().next().unwrap_or(c),
+                    index,
+                }
+            }
+            _ => DeserializeError::OddLength,
+        })
     }
 }
 
-/// The license key generator.
-#[derive(Debug)]
-pub struct Generator<T: KeyHasher> {
-    hasher: T,
-    iv: Vec<(u64, u64, u64)>,
+/// A license key format that can be selected at runtime.
+///
+/// Each format dispatches to its corresponding [`Serializer`].
+///
+/// [`Serializer`]: trait.Serializer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyFormat {
+    /// Hex strings. See [`HexFormat`].
+    ///
+    /// [`HexFormat`]: struct.HexFormat.html
+    Hex,
+    /// Crockford base32 strings. See [`Base32Format`].
+    ///
+    /// [`Base32Format`]: struct.Base32Format.html
+    Base32,
+    /// Base58 strings. See [`Base58Format`].
+    ///
+    /// [`Base58Format`]: struct.Base58Format.html
+    Base58,
+    /// URL-safe Base64 strings. See [`Base64UrlFormat`].
+    ///
+    /// [`Base64UrlFormat`]: struct.Base64UrlFormat.html
+    Base64Url,
 }
 
-impl<T: KeyHasher> Generator<T> {
-    /// Creates a new license key generator.
-    pub fn new(hasher: T, iv: Vec<(u64, u64, u64)>) -> Self {
-        Self { hasher, iv }
+impl KeyFormat {
+    /// Gets all supported formats.
+    pub fn all() -> &'static [KeyFormat] {
+        &[
+            KeyFormat::Hex,
+            KeyFormat::Base32,
+            KeyFormat::Base58,
+            KeyFormat::Base64Url,
+        ]
     }
 
-    /// Creates a new license key with the specified seed.
-    pub fn generate(&self, seed: u64) -> LicenseKey {
-        // Get the license key as a byte array
-        let mut input = seed.to_be_bytes().to_vec();
-        for iv in self.iv.iter() {
-            for byte in self
-                .hasher
-                .hash(seed, iv.0, iv.1, iv.2)
-                .to_be_bytes()
-                .to_vec()
-            {
-                input.push(byte);
-            }
+    /// Gets the name of the format.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyFormat::Hex => "hex",
+            KeyFormat::Base32 => "base32",
+            KeyFormat::Base58 => "base58",
+            KeyFormat::Base64Url => "base64url",
         }
+    }
 
-        // Calculate the checksum for the license key
-        let checksum = calculate_checksum(&input);
-        for byte in checksum.iter() {
-            input.push(*byte);
+    /// Gets whether or not the format ignores the case of the input.
+    /// See [`Serializer::case_insensitive`].
+    ///
+    /// [`Serializer::case_insensitive`]: trait.Serializer.html#method.case_insensitive
+    pub fn case_insensitive(&self) -> bool {
+        match self {
+            KeyFormat::Hex => HexFormat.case_insensitive(),
+            KeyFormat::Base32 => Base32Format.case_insensitive(),
+            KeyFormat::Base58 => Base58Format.case_insensitive(),
+            KeyFormat::Base64Url => Base64UrlFormat.case_insensitive(),
+        }
+    }
+
+    /// Serializes a license key to a string.
+    pub fn serialize(&self, key: &LicenseKey) -> String {
+        match self {
+            KeyFormat::Hex => HexFormat.serialize(key),
+            KeyFormat::Base32 => Base32Format.serialize(key),
+            KeyFormat::Base58 => Base58Format.serialize(key),
+            KeyFormat::Base64Url => Base64UrlFormat.serialize(key),
         }
+    }
 
-        LicenseKey::new(input)
+    /// Deserializes a license key into a byte vector.
+    pub fn deserialize(&self, input: &str) -> Result<Vec<u8>, DeserializeError> {
+        match self {
+            KeyFormat::Hex => HexFormat.deserialize(input),
+            KeyFormat::Base32 => Base32Format.deserialize(input),
+            KeyFormat::Base58 => Base58Format.deserialize(input),
+            KeyFormat::Base64Url => Base64UrlFormat.deserialize(input),
+        }
     }
 }
 
-/// Representation of a license key status.
-#[derive(Debug, PartialEq)]
-pub enum Status {
-    /// The license is valid.
-    Valid,
-    /// The license is invalid.
-    Invalid,
-    /// The license has been blocked.
-    Blocked,
-    /// The license has been forged.
-    Forged,
+/// Describes the optional fields that are part of a license key.
+///
+/// Optional fields are stored between the seed and the payload.
+/// Since the layout can't be inferred from the key bytes alone,
+/// it must be provided when parsing a key that isn't a plain one.
+/// Use a [`LayoutRegistry`] to pick the layout by the version of the key.
+///
+/// [`LayoutRegistry`]: struct.LayoutRegistry.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KeyLayout {
+    /// The length of the seed. See [`Seed`].
+    ///
+    /// [`Seed`]: trait.Seed.html
+    pub seed_length: SeedLength,
+    /// Whether or not the key contains a checksum of the seed.
+    pub seed_checksum: bool,
+    /// Whether or not the key contains a version.
+    pub version: bool,
+    /// Whether or not the key contains a flags byte, which tells whether
+    /// or not the key is a trial key. See [`Generator::include_flags`].
+    ///
+    /// [`Generator::include_flags`]: struct.Generator.html#method.include_flags
+    pub flags: bool,
+    /// Whether or not the key contains a device index.
+    pub device_index: bool,
+    /// Whether or not the key contains a region code.
+    pub region: bool,
+    /// Whether or not the key contains an expiration time.
+    pub expiry: bool,
+    /// Whether or not the key contains an issue date.
+    pub issue_date: bool,
+    /// Whether or not the key contains a feature bitmask.
+    pub features: bool,
+    /// Whether or not the key is bound to a machine. The machine
+    /// identifier isn't stored in the key, so this doesn't add any bytes.
+    pub machine: bool,
+    /// Whether or not the key contains signed claims.
+    pub claims: bool,
+    /// Whether or not the key ends with a 4-byte checksum
+    /// instead of the regular 2-byte checksum.
+    pub long_checksum: bool,
+    /// The length of every segment in the payload.
+    pub segment_length: SegmentLength,
 }
 
-/// Represents a license key byte check
-/// that should be used during validation.
-#[derive(Debug)]
-pub struct ByteCheck {
-    pub ordinal: u8,
-    pub a: u64,
-    pub b: u64,
-    pub c: u64,
+/// The length of the payload segments that every initialization
+/// vector triplet results in. See [`Generator::use_segment_length`].
+///
+/// A forger has a 1 in 256 chance of guessing a one-byte segment,
+/// but only a 1 in 65536 chance of guessing a two-byte segment.
+///
+/// [`Generator::use_segment_length`]: struct.Generator.html#method.use_segment_length
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SegmentLength {
+    /// One byte per segment. This is the default.
+    #[default]
+    One,
+    /// Two bytes per segment.
+    Two,
+    /// Four bytes per segment.
+    Four,
 }
 
-impl ByteCheck {
-    /// Creates a new byte check.
-    pub fn new(ordinal: u8, iv: (u64, u64, u64)) -> Self {
-        Self {
-            ordinal,
-            a: iv.0,
-            b: iv.1,
-            c: iv.2,
+impl SegmentLength {
+    /// Gets the number of bytes in a segment.
+    pub fn bytes(&self) -> usize {
+        match self {
+            SegmentLength::One => 1,
+            SegmentLength::Two => 2,
+            SegmentLength::Four => 4,
         }
     }
 }
 
-/// The license key verifier.
-#[derive(Debug)]
-pub struct Verifier<T: KeyHasher> {
-    hasher: T,
-    checks: Vec<ByteCheck>,
-    blocklist: Vec<u64>,
-}
+impl KeyLayout {
+    pub(crate) fn version_offset(&self) -> usize {
+        let mut offset = self.seed_length.bytes();
+        if self.seed_checksum {
+            offset += SEED_CHECKSUM_BYTE_LENGTH as usize;
+        }
+        offset
+    }
 
-impl<T: KeyHasher> Verifier<T> {
-    /// Creates a new license key verifier.
-    pub fn new(hasher: T, checks: Vec<ByteCheck>) -> Self {
-        Self {
-            hasher,
-            checks,
-            blocklist: Vec::new(),
+    pub(crate) fn flags_offset(&self) -> usize {
+        let mut offset = self.version_offset();
+        if self.version {
+            offset += VERSION_BYTE_LENGTH as usize;
         }
+        offset
     }
 
-    /// Blocks the specified seed from being used.
-    pub fn block(&mut self, seed: u64) {
-        self.blocklist.push(seed)
+    pub(crate) fn device_index_offset(&self) -> usize {
+        let mut offset = self.flags_offset();
+        if self.flags {
+            offset += FLAGS_BYTE_LENGTH as usize;
+        }
+        offset
     }
 
-    /// Perform verification on the provided license key.
-    pub fn verify(&self, key: &LicenseKey) -> Status {
-        // Validate the checksum
-        let checksum = key.calculate_checksum().to_vec();
-        if checksum != key.get_checksum() {
-            return Status::Invalid;
+    pub(crate) fn region_offset(&self) -> usize {
+        let mut offset = self.device_index_offset();
+        if self.device_index {
+            offset += DEVICE_INDEX_BYTE_LENGTH as usize;
         }
+        offset
+    }
 
-        // Blocked key?
-        let seed = key.get_seed();
-        for blocked_seed in self.blocklist.iter() {
-            if seed == *blocked_seed {
-                return Status::Blocked;
-            }
+    pub(crate) fn expiry_offset(&self) -> usize {
+        let mut offset = self.region_offset();
+        if self.region {
+            offset += REGION_BYTE_LENGTH as usize;
         }
+        offset
+    }
 
-        for check in self.checks.iter() {
-            match key.get_byte(check.ordinal as usize) {
-                Some(value) => {
-                    if value != self.hasher.hash(seed, check.a, check.b, check.c) {
-                        // Values did not match, but the checksum
-                        // was correct, so this is a forged license key
-                        return Status::Forged;
-                    }
-                }
-                None => {
-                    // If we couldn't get the byte from the license
-                    // the license is invalid.
-                    return Status::Invalid;
-                }
-            }
+    pub(crate) fn issue_date_offset(&self) -> usize {
+        let mut offset = self.expiry_offset();
+        if self.expiry {
+            offset += EXPIRY_BYTE_LENGTH as usize;
         }
+        offset
+    }
 
-        Status::Valid
+    pub(crate) fn features_offset(&self) -> usize {
+        let mut offset = self.issue_date_offset();
+        if self.issue_date {
+            offset += ISSUE_DATE_BYTE_LENGTH as usize;
+        }
+        offset
     }
-}
 
-fn calculate_checksum(key: &[u8]) -> [u8; 2] {
-    let mut left = 0x56_u16;
-    let mut right = 0xAF_u16;
+    pub(crate) fn claims_offset(&self) -> usize {
+        let mut offset = self.features_offset();
+        if self.features {
+            offset += FEATURES_BYTE_LENGTH as usize;
+        }
+        offset
+    }
 
-    for byte in key.iter() {
-        right += *byte as u16;
-        if right > 0xFF {
-            right -= 0xFF;
+    pub(crate) fn payload_offset(&self) -> usize {
+        let mut offset = self.claims_offset();
+        if self.claims {
+            offset += (CLAIMS_BYTE_LENGTH + SIGNATURE_BYTE_LENGTH) as usize;
         }
-        left += right;
-        if left > 0xFF {
-            left -= 0xFF;
+        offset
+    }
+
+    pub(crate) fn checksum_length(&self) -> usize {
+        if self.long_checksum {
+            LONG_CHECKSUM_BYTE_LENGTH as usize
+        } else {
+            CHECKSUM_BYTE_LENGTH as usize
         }
     }
-    ((left << 8) + right).to_be_bytes()
+
+    /// Calculates the expected length (in bytes) of a license key
+    /// with this layout and the specified payload length.
+    pub fn key_length(&self, payload_length: usize) -> usize {
+        self.payload_offset()
+            + (payload_length * self.segment_length.bytes())
+            + self.checksum_length()
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::KeyHasher;
-    use crate::Generator;
+/// Structured claims that can be embedded in a license key.
+///
+/// Claims are signed with a secret when the key is generated,
+/// which makes them impossible to alter without knowing the secret.
+/// Signing and verifying claims requires the `hmac` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Claims {
+    /// The time (as a Unix timestamp) when the license expires,
+    /// or `0` if the license never expires.
+    pub expires_at: u32,
+    /// A bitmask of the features that the license unlocks.
+    pub features: u32,
+    /// An identifier for the product the license belongs to.
+    pub product: u16,
+}
 
-    #[derive(Default)]
-    pub struct TestHasher {}
-    impl KeyHasher for TestHasher {
-        fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
-            ((seed ^ a ^ b ^ c) & 0xFF) as u8
+impl Claims {
+    pub(crate) fn to_bytes(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(CLAIMS_BYTE_LENGTH as usize);
+        bytes.extend_from_slice(&self.expires_at.to_be_bytes());
+        bytes.extend_from_slice(&self.features.to_be_bytes());
+        bytes.extend_from_slice(&self.product.to_be_bytes());
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            expires_at: u32::from_be_bytes(bytes[0..4].try_into().unwrap()),
+            features: u32::from_be_bytes(bytes[4..8].try_into().unwrap()),
+            product: u16::from_be_bytes(bytes[8..10].try_into().unwrap()),
         }
     }
+}
 
-    pub fn generate_key(seed: u64) -> LicenseKey {
-        let generator = Generator::new(
-            TestHasher::default(),
-            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)],
-        );
-        generator.generate(seed)
+#[derive(Default)]
+pub(crate) struct KeyFields {
+    pub seed: Option<(SeedLength, Vec<u8>)>,
+    pub device_index: Option<u32>,
+    pub region: Option<u16>,
+    pub expires_at: Option<u32>,
+    pub issue_date: Option<u16>,
+    pub features: Option<u32>,
+    pub machine_id: Option<u64>,
+    pub trial: bool,
+    pub claims: Option<(Claims, [u8; SIGNATURE_BYTE_LENGTH as usize])>,
+}
+
+/// Represents a generated or parsed license key.
+#[derive(Debug, Clone)]
+pub struct LicenseKey {
+    bytes: Vec<u8>,
+    layout: KeyLayout,
+}
+
+impl LicenseKey {
+    pub(crate) fn new(bytes: Vec<u8>, layout: KeyLayout) -> Self {
+        Self { bytes, layout }
     }
 
-    pub fn create_verifier() -> Verifier<TestHasher> {
-        Verifier::new(
-            TestHasher::default(),
-            vec![
-                ByteCheck::new(0, (114, 83, 170)),
-                ByteCheck::new(2, (69, 14, 202)),
-            ],
-        )
+    /// Deserializes a [`&str`] into a license key by using the
+    /// provided [`Serializer`].
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn parse<T: Serializer + ?Sized>(
+        input: &str,
+        serializer: &T,
+    ) -> Result<LicenseKey, DeserializeError> {
+        LicenseKey::parse_with_layout(input, serializer, KeyLayout::default())
     }
 
-    #[test]
-    pub fn valid_key_should_be_valid() {
-        // Given
-        let key = generate_key(12345);
-        let verifier = create_verifier();
+    /// Deserializes a [`&str`] into a license key with the specified
+    /// [`KeyLayout`] by using the provided [`Serializer`].
+    ///
+    /// If the license key is too short to contain the seed, the optional
+    /// fields of the layout and the checksum, [`DeserializeError::TooShort`]
+    /// is returned.
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/std/primitive.str.html
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`Serializer`]: trait.Serializer.html
+    /// [`DeserializeError::TooShort`]: enum.DeserializeError.html#variant.TooShort
+    pub fn parse_with_layout<T: Serializer + ?Sized>(
+        input: &str,
+        serializer: &T,
+        layout: KeyLayout,
+    ) -> Result<LicenseKey, DeserializeError> {
+        LicenseKey::from_vec(serializer.deserialize(input)?, layout)
+    }
 
-        // When
-        let result = verifier.verify(&key);
+    /// Creates a license key from raw bytes, without any string encoding.
+    ///
+    /// This is the most compact way to transport a license key, such as
+    /// in a binary license file. Use [`as_bytes`] or [`get_bytes`] to get
+    /// the raw bytes of a license key.
+    ///
+    /// [`as_bytes`]: struct.LicenseKey.html#method.as_bytes
+    /// [`get_bytes`]: struct.LicenseKey.html#method.get_bytes
+    pub fn from_bytes(bytes: &[u8]) -> Result<LicenseKey, DeserializeError> {
+        LicenseKey::from_bytes_with_layout(bytes, KeyLayout::default())
+    }
 
-        // Then
-        assert_eq!(Status::Valid, result);
+    /// Creates a license key with the specified [`KeyLayout`] from raw bytes.
+    ///
+    /// Just like [`LicenseKey::parse_with_layout`], an error is returned
+    /// if the bytes are too short for the layout.
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`LicenseKey::parse_with_layout`]: struct.LicenseKey.html#method.parse_with_layout
+    pub fn from_bytes_with_layout(
+        bytes: &[u8],
+        layout: KeyLayout,
+    ) -> Result<LicenseKey, DeserializeError> {
+        LicenseKey::from_parts(bytes.to_vec(), layout)
     }
 
-    #[test]
-    pub fn valid_but_blocked_key_should_return_error() {
-        // Given
-        let key = generate_key(12345);
-        let mut verifier = create_verifier();
-        verifier.block(12345);
+    /// Creates a license key with the specified [`KeyLayout`] from raw
+    /// bytes, taking ownership of the bytes instead of copying them.
+    /// See [`LicenseKey::from_bytes_with_layout`].
+    ///
+    /// Use [`LicenseKey::into_parts`] to get the bytes and layout back.
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`LicenseKey::from_bytes_with_layout`]: struct.LicenseKey.html#method.from_bytes_with_layout
+    /// [`LicenseKey::into_parts`]: struct.LicenseKey.html#method.into_parts
+    pub fn from_parts(bytes: Vec<u8>, layout: KeyLayout) -> Result<LicenseKey, DeserializeError> {
+        if bytes.is_empty() {
+            return Err(DeserializeError::Empty);
+        }
+        LicenseKey::from_vec(bytes, layout)
+    }
 
-        // When
-        let result = verifier.verify(&key);
+    fn from_vec(bytes: Vec<u8>, layout: KeyLayout) -> Result<LicenseKey, DeserializeError> {
+        let minimum = layout.key_length(0);
+        if bytes.len() < minimum {
+            return Err(DeserializeError::TooShort {
+                length: bytes.len(),
+                minimum,
+            });
+        }
+        Ok(LicenseKey::new(bytes, layout))
+    }
 
-        // Then
-        assert_eq!(Status::Blocked, result);
+    /// Serializes the license key into a [`String`] by using the 
+    /// provided [`Serializer`].
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn serialize<T: Serializer + ?Sized>(&self, serializer: &T) -> String {
+        serializer.serialize(self)
+    }
+
+    /// Gets the individual bytes that makes up the license key.
+    ///
+    /// This returns a copy of the bytes. Use [`as_bytes`] to avoid
+    /// leaving copies of the license key in memory.
+    ///
+    /// [`as_bytes`]: struct.LicenseKey.html#method.as_bytes
+    pub fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    /// Gets the individual bytes that makes up the license key,
+    /// without copying them.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the license key and returns its bytes without copying them.
+    ///
+    /// With the `zeroize` feature, the returned bytes are no longer
+    /// cleared automatically, so clear them once they're not needed.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.into_parts().0
+    }
+
+    /// Consumes the license key and returns its bytes and its layout.
+    /// See [`LicenseKey::into_bytes`].
+    ///
+    /// [`LicenseKey::into_bytes`]: struct.LicenseKey.html#method.into_bytes
+    pub fn into_parts(mut self) -> (Vec<u8>, KeyLayout) {
+        (core::mem::take(&mut self.bytes), self.layout)
+    }
+
+    /// Gets the layout of the license key.
+    pub fn layout(&self) -> KeyLayout {
+        self.layout
+    }
+
+    /// Gets a short fingerprint of the license key that is safe to log.
+    ///
+    /// The fingerprint is the first 8 hex characters of the SHA-256 hash
+    /// of the license key bytes, which makes it possible to tell license
+    /// keys apart in logs without revealing the license key or its seed.
+    pub fn fingerprint(&self) -> String {
+        use sha2::{Digest, Sha256};
+        hex::encode(&Sha256::digest(&self.bytes)[..4])
+    }
+
+    /// Gets the seed of the license key, or `None` if
+    /// the license key is too short to contain a seed.
+    ///
+    /// License keys with a seed that isn't 64 bits long don't have a 64-bit
+    /// seed, so `None` is returned for them as well. Use [`LicenseKey::seed_as`]
+    /// or [`LicenseKey::wide_seed`] to get the full seed.
+    ///
+    /// [`LicenseKey::seed_as`]: struct.LicenseKey.html#method.seed_as
+    /// [`LicenseKey::wide_seed`]: struct.LicenseKey.html#method.wide_seed
+    pub fn seed(&self) -> Option<u64> {
+        self.seed_as::<u64>()
+    }
+
+    /// Gets the seed of the license key as the specified [`Seed`] type,
+    /// or `None` if the license key doesn't have a seed of that length
+    /// or is too short to contain a seed. See [`Generator::generate_seed`].
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`Generator::generate_seed`]: struct.Generator.html#method.generate_seed
+    pub fn seed_as<S: Seed>(&self) -> Option<S> {
+        if self.layout.seed_length != S::LENGTH {
+            return None;
+        }
+        S::from_bytes(self.bytes.get(0..S::LENGTH.bytes())?)
+    }
+
+    /// Checks whether or not the two license keys were generated from the
+    /// same seed, such as license keys for different devices or with
+    /// different expiration times for the same customer.
+    ///
+    /// Only the seeds are compared, which means that the license keys
+    /// don't have to be valid. If the seeds have different lengths,
+    /// or either license key is too short to contain a seed, this
+    /// returns `false`.
+    pub fn same_seed(&self, other: &LicenseKey) -> bool {
+        let seed_length = self.layout.seed_length;
+        if seed_length != other.layout.seed_length {
+            return false;
+        }
+        match (
+            self.bytes.get(0..seed_length.bytes()),
+            other.bytes.get(0..seed_length.bytes()),
+        ) {
+            (Some(seed), Some(other_seed)) => seed == other_seed,
+            _ => false,
+        }
+    }
+
+    /// Gets the full 128-bit seed of the license key, or `None` if
+    /// the license key is too short to contain a seed.
+    /// See [`Generator::generate_wide`].
+    ///
+    /// For license keys with a shorter seed, the seed is widened.
+    ///
+    /// [`Generator::generate_wide`]: struct.Generator.html#method.generate_wide
+    pub fn wide_seed(&self) -> Option<u128> {
+        match self.layout.seed_length {
+            SeedLength::Four => self.seed_as::<u32>().map(u128::from),
+            SeedLength::Eight => self.seed_as::<u64>().map(u128::from),
+            SeedLength::Sixteen => self.seed_as::<u128>(),
+        }
+    }
+
+    /// Gets the payload of the license key, or `None` if the
+    /// license key is too short to contain its layout and checksum.
+    pub fn payload(&self) -> Option<&[u8]> {
+        if self.bytes.len() < self.layout.key_length(0) {
+            return None;
+        }
+        let end = self.bytes.len() - self.layout.checksum_length();
+        Some(&self.bytes[self.layout.payload_offset()..end])
+    }
+
+    /// Checks whether or not the checksum of the license key is correct,
+    /// without performing any byte checks.
+    ///
+    /// This is a cheap way to reject corrupt or mistyped license keys
+    /// before calling the verifier. A license key that is too short to
+    /// contain a checksum is considered corrupt. Only license keys created
+    /// with the default checksum, without the length folded into it, can
+    /// be checked; use a [`Verifier`] for anything else.
+    ///
+    /// [`Verifier`]: struct.Verifier.html
+    pub fn verify_checksum(&self) -> bool {
+        if self.bytes.len() < self.layout.key_length(0) {
+            return false;
+        }
+        constant_time_eq(&self.calculate_checksum(None, false), self.get_checksum())
+    }
+
+    /// Checks whether or not the seed matches the seed checksum.
+    ///
+    /// This makes it possible to reject a mistyped seed without
+    /// verifying the rest of the license key. If the license key
+    /// doesn't contain a seed checksum, this returns `false`.
+    pub fn seed_checksum_ok(&self) -> bool {
+        if !self.layout.seed_checksum {
+            return false;
+        }
+        let seed_length = self.layout.seed_length.bytes();
+        match self.bytes.get(seed_length) {
+            Some(checksum) => *checksum == calculate_seed_checksum(&self.bytes[0..seed_length]),
+            None => false,
+        }
+    }
+
+    /// Gets the version of the license key, if the license key
+    /// contains a version. See [`Generator::include_version`].
+    ///
+    /// [`Generator::include_version`]: struct.Generator.html#method.include_version
+    pub fn version(&self) -> Option<u8> {
+        if !self.layout.version {
+            return None;
+        }
+        self.bytes.get(self.layout.version_offset()).copied()
+    }
+
+    /// Gets the flags byte of the license key, if the
+    /// license key contains one. See [`Generator::include_flags`].
+    ///
+    /// [`Generator::include_flags`]: struct.Generator.html#method.include_flags
+    pub fn flags(&self) -> Option<u8> {
+        if !self.layout.flags {
+            return None;
+        }
+        self.bytes.get(self.layout.flags_offset()).copied()
+    }
+
+    /// Checks whether or not the license key is a trial license key.
+    /// See [`Generator::generate_trial`].
+    ///
+    /// License keys without a flags byte are never trials. Only trust
+    /// the trial flag once the license key has been verified.
+    ///
+    /// [`Generator::generate_trial`]: struct.Generator.html#method.generate_trial
+    pub fn is_trial(&self) -> bool {
+        self.flags().is_some_and(|flags| flags & TRIAL_FLAG != 0)
+    }
+
+    /// Gets the device index of the license key, if the
+    /// license key was generated for a specific device.
+    pub fn device_index(&self) -> Option<u32> {
+        if !self.layout.device_index {
+            return None;
+        }
+        let start = self.layout.device_index_offset();
+        let end = start + DEVICE_INDEX_BYTE_LENGTH as usize;
+        let bytes = self.bytes.get(start..end)?.try_into().unwrap();
+        Some(u32::from_be_bytes(bytes))
+    }
+
+    /// Gets the region code of the license key, if the
+    /// license key is only valid within a specific region.
+    pub fn region(&self) -> Option<u16> {
+        if !self.layout.region {
+            return None;
+        }
+        let start = self.layout.region_offset();
+        let end = start + REGION_BYTE_LENGTH as usize;
+        let bytes = self.bytes.get(start..end)?.try_into().unwrap();
+        Some(u16::from_be_bytes(bytes))
+    }
+
+    /// Gets the time (as a Unix timestamp) when the license key
+    /// expires, if the license key contains an expiration time.
+    /// See [`Generator::generate_with_expiry`].
+    ///
+    /// If the license key is too short to contain the expiration
+    /// time, `None` is returned.
+    ///
+    /// [`Generator::generate_with_expiry`]: struct.Generator.html#method.generate_with_expiry
+    pub fn expires_at(&self) -> Option<u32> {
+        if !self.layout.expiry {
+            return None;
+        }
+        let start = self.layout.expiry_offset();
+        let end = start + EXPIRY_BYTE_LENGTH as usize;
+        let bytes = self.bytes.get(start..end)?.try_into().unwrap();
+        Some(u32::from_be_bytes(bytes))
+    }
+
+    /// Gets the time (as a Unix timestamp) when the license key was
+    /// issued, if the license key contains an issue date.
+    /// See [`Generator::generate_with_issue_date`].
+    ///
+    /// Only the day is stored, so the time is midnight (UTC) of the day
+    /// the license key was issued. If the license key is too short to
+    /// contain the issue date, `None` is returned. Only trust the issue
+    /// date once the license key has been verified.
+    ///
+    /// [`Generator::generate_with_issue_date`]: struct.Generator.html#method.generate_with_issue_date
+    pub fn issued_at(&self) -> Option<u64> {
+        self.get_issue_date()
+            .map(|days| days as u64 * SECONDS_PER_DAY as u64)
+    }
+
+    pub(crate) fn get_issue_date(&self) -> Option<u16> {
+        if !self.layout.issue_date {
+            return None;
+        }
+        let start = self.layout.issue_date_offset();
+        let end = start + ISSUE_DATE_BYTE_LENGTH as usize;
+        let bytes = self.bytes.get(start..end)?.try_into().unwrap();
+        Some(u16::from_be_bytes(bytes))
+    }
+
+    /// Gets the feature bitmask of the license key.
+    /// See [`Generator::generate_with_features`].
+    ///
+    /// If the license key doesn't contain a feature bitmask, or is too short
+    /// to contain it, `0` is returned, which means that no features are
+    /// unlocked. Only trust the bitmask once the license key has been
+    /// verified.
+    ///
+    /// [`Generator::generate_with_features`]: struct.Generator.html#method.generate_with_features
+    pub fn features(&self) -> u32 {
+        if !self.layout.features {
+            return 0;
+        }
+        let start = self.layout.features_offset();
+        let end = start + FEATURES_BYTE_LENGTH as usize;
+        match self.bytes.get(start..end) {
+            Some(bytes) => u32::from_be_bytes(bytes.try_into().unwrap()),
+            None => 0,
+        }
+    }
+
+    /// Gets the claims embedded in the license key, if any.
+    ///
+    /// Note that the claims are not guaranteed to be authentic
+    /// unless the license key has been verified with a [`ClaimSigner`].
+    ///
+    /// [`ClaimSigner`]: struct.ClaimSigner.html
+    pub fn claims(&self) -> Option<Claims> {
+        if !self.layout.claims {
+            return None;
+        }
+        let start = self.layout.claims_offset();
+        let end = start + CLAIMS_BYTE_LENGTH as usize;
+        Some(Claims::from_bytes(self.bytes.get(start..end)?))
+    }
+
+    #[cfg(feature = "hmac")]
+    pub(crate) fn get_claims_signature(&self) -> Option<&[u8]> {
+        if !self.layout.claims {
+            return None;
+        }
+        let start = self.layout.claims_offset() + CLAIMS_BYTE_LENGTH as usize;
+        let end = start + SIGNATURE_BYTE_LENGTH as usize;
+        self.bytes.get(start..end)
+    }
+
+    pub(crate) fn get_segment(&self, ordinal: usize) -> Option<&[u8]> {
+        // The payload ends where the checksum starts
+        let segment_length = self.layout.segment_length.bytes();
+        let index = self.layout.payload_offset() + (ordinal * segment_length);
+        let checksum_length = self.layout.checksum_length();
+        let payload_end = self.bytes.len().checked_sub(checksum_length)?;
+        if index + segment_length > payload_end {
+            return None;
+        }
+        Some(&self.bytes[index..index + segment_length])
+    }
+
+    pub(crate) fn get_checksum(&self) -> &[u8] {
+        &self.bytes[self.body_length()..]
+    }
+
+    pub(crate) fn get_seed<T: KeyHasher + ?Sized>(&self, hasher: &T) -> u64 {
+        // License keys that are too short to contain a seed never
+        // pass verification, so any seed will do for them.
+        let seed_length = self.layout.seed_length;
+        match self.bytes.get(0..seed_length.bytes()) {
+            Some(bytes) => seed_length.hash_seed(hasher, bytes),
+            None => 0,
+        }
+    }
+
+    fn body_length(&self) -> usize {
+        self.bytes
+            .len()
+            .saturating_sub(self.layout.checksum_length())
+    }
+
+    pub(crate) fn get_hash_seed<T: KeyHasher + ?Sized>(&self, hasher: &T) -> u64 {
+        let seed = self.get_seed(hasher);
+        let mut hash_seed = match self.device_index() {
+            Some(device_index) => device_seed(seed, device_index),
+            None => seed,
+        };
+        if let Some(region) = self.region() {
+            hash_seed = region_seed(hash_seed, region);
+        }
+        if let Some(expires_at) = self.expires_at() {
+            hash_seed = expiry_seed(hash_seed, expires_at);
+        }
+        if let Some(issue_date) = self.get_issue_date() {
+            hash_seed = issue_date_seed(hash_seed, issue_date);
+        }
+        if self.layout.features {
+            hash_seed = features_seed(hash_seed, self.features());
+        }
+        if let Some(flags) = self.flags() {
+            hash_seed = flags_seed(hash_seed, flags);
+        }
+        hash_seed
+    }
+
+    pub(crate) fn calculate_checksum(
+        &self,
+        checksum: Option<&dyn Checksum>,
+        fold_length: bool,
+    ) -> Vec<u8> {
+        let body = &self.bytes[0..self.body_length()];
+        compute_checksum(checksum, body, fold_length, self.layout.long_checksum)
+    }
+}
+
+/// Formats the license key as a hex string.
+impl core::fmt::Display for LicenseKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&HexFormat.serialize(self))
+    }
+}
+
+/// Compares the bytes of the license keys, regardless of their layouts.
+impl PartialEq for LicenseKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for LicenseKey {}
+
+/// Hashes the bytes of the license key, regardless of its layout.
+impl core::hash::Hash for LicenseKey {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bytes.hash(state);
+    }
+}
+
+/// Parses a license key with the default layout from a hex string.
+impl core::str::FromStr for LicenseKey {
+    type Err = DeserializeError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        LicenseKey::parse(input, &HexFormat)
+    }
+}
+
+/// Serializes the license key as a hex string.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LicenseKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&HexFormat.serialize(self))
+    }
+}
+
+/// Deserializes the license key from a hex string.
+///
+/// Since the layout can't be inferred from the string alone,
+/// the license key gets the default [`KeyLayout`].
+///
+/// [`KeyLayout`]: struct.KeyLayout.html
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LicenseKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+        LicenseKey::parse(&input, &HexFormat).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Wipes the bytes of the license key.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for LicenseKey {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+/// Wipes the bytes of the license key when it's dropped.
+///
+/// Copies returned by [`get_bytes`] or a [`Serializer`] are not wiped.
+///
+/// [`get_bytes`]: struct.LicenseKey.html#method.get_bytes
+/// [`Serializer`]: trait.Serializer.html
+#[cfg(feature = "zeroize")]
+impl Drop for LicenseKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for LicenseKey {}
+
+/// The license key generator.
+#[derive(Debug)]
+pub struct Generator<T: KeyHasher> {
+    hasher: T,
+    iv: Vec<(u64, u64, u64)>,
+    decoys: Vec<(u8, (u64, u64, u64))>,
+    seed_checksum: bool,
+    version: Option<u8>,
+    flags: bool,
+    fold_length: bool,
+    long_checksum: bool,
+    checksum: Option<Box<dyn Checksum>>,
+    padded_length: usize,
+    segment_length: SegmentLength,
+}
+
+impl<T: KeyHasher> Generator<T> {
+    /// Creates a new license key generator.
+    ///
+    /// An empty initialization vector results in license keys without a
+    /// payload, which are invalid for every verifier with byte checks.
+    /// Use [`Generator::try_new`] to reject such a misconfiguration.
+    ///
+    /// [`Generator::try_new`]: struct.Generator.html#method.try_new
+    pub fn new(hasher: T, iv: Vec<(u64, u64, u64)>) -> Self {
+        Self {
+            hasher,
+            iv,
+            decoys: Vec::new(),
+            seed_checksum: false,
+            version: None,
+            flags: false,
+            fold_length: false,
+            long_checksum: false,
+            checksum: None,
+            padded_length: 0,
+            segment_length: SegmentLength::One,
+        }
+    }
+
+    /// Creates a new license key generator, unless the initialization
+    /// vector is empty or contains any of the example triplets that
+    /// have been published in the documentation of this crate.
+    ///
+    /// Using a published initialization vector is almost certainly
+    /// a mistake, since anyone can use it to generate license keys.
+    pub fn try_new(hasher: T, iv: Vec<(u64, u64, u64)>) -> Result<Self, GenError> {
+        if iv.is_empty() {
+            return Err(GenError::EmptyIv);
+        }
+        if iv.iter().any(|triplet| PUBLISHED_IV.contains(triplet)) {
+            return Err(GenError::PublishedIv);
+        }
+        Ok(Self::new(hasher, iv))
+    }
+
+    /// Pads the payload of all generated license keys with additional
+    /// bytes until it contains at least `length` bytes.
+    ///
+    /// This hides how long the initialization vector is, and makes all
+    /// license keys the same length. The padding bytes are derived from
+    /// the seed using the hasher and are covered by the checksum, but
+    /// are never checked by the verifier.
+    pub fn pad_payload(&mut self, length: usize) {
+        self.padded_length = length;
+    }
+
+    /// Folds the length of the license key into the checksum.
+    /// This is disabled by default.
+    ///
+    /// This makes it harder to truncate a license key and recalculate a
+    /// valid checksum. The verifier must have the same setting enabled
+    /// using [`Verifier::fold_length_into_checksum`].
+    ///
+    /// [`Verifier::fold_length_into_checksum`]: struct.Verifier.html#method.fold_length_into_checksum
+    pub fn fold_length_into_checksum(&mut self, enabled: bool) {
+        self.fold_length = enabled;
+    }
+
+    /// Ends all generated license keys with a 4-byte checksum instead
+    /// of the regular 2-byte checksum. This is disabled by default.
+    ///
+    /// A random string is far less likely to pass a long checksum.
+    /// License keys with a long checksum must be parsed with a
+    /// [`KeyLayout`] where `long_checksum` is set.
+    ///
+    /// This has no effect when a custom [`Checksum`] is set, since the
+    /// length of the checksum is then the length of its output.
+    /// See [`Generator::use_checksum`].
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`Checksum`]: trait.Checksum.html
+    /// [`Generator::use_checksum`]: struct.Generator.html#method.use_checksum
+    pub fn use_long_checksum(&mut self, enabled: bool) {
+        self.long_checksum = enabled;
+    }
+
+    /// Sets the length of the payload segment that every initialization
+    /// vector triplet results in. The default is one byte per segment.
+    ///
+    /// Wider segments make it harder to forge a license key, at the cost of
+    /// a longer license key. License keys with wider segments must be parsed
+    /// with a [`KeyLayout`] where `segment_length` is set.
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    pub fn use_segment_length(&mut self, segment_length: SegmentLength) {
+        self.segment_length = segment_length;
+    }
+
+    /// Sets the [`Checksum`] algorithm that is used for all generated
+    /// license keys, instead of the default one. The verifier must use the
+    /// same algorithm using [`Verifier::use_checksum`].
+    ///
+    /// The output of the checksum decides whether or not the license keys
+    /// have a long checksum, regardless of [`Generator::use_long_checksum`].
+    /// A 4-byte output is a long checksum, which means that the license keys
+    /// must be parsed with a [`KeyLayout`] where `long_checksum` is set.
+    ///
+    /// [`Checksum`]: trait.Checksum.html
+    /// [`Verifier::use_checksum`]: struct.Verifier.html#method.use_checksum
+    /// [`Generator::use_long_checksum`]: struct.Generator.html#method.use_long_checksum
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    pub fn use_checksum(&mut self, checksum: impl Checksum + 'static) {
+        self.checksum = Some(Box::new(checksum));
+    }
+
+    /// Includes a checksum of the seed right after the seed in all
+    /// generated license keys. This is disabled by default.
+    ///
+    /// See [`LicenseKey::seed_checksum_ok`].
+    ///
+    /// [`LicenseKey::seed_checksum_ok`]: struct.LicenseKey.html#method.seed_checksum_ok
+    pub fn include_seed_checksum(&mut self, enabled: bool) {
+        self.seed_checksum = enabled;
+    }
+
+    /// Includes the specified version right after the seed (and
+    /// the seed checksum) in all generated license keys.
+    ///
+    /// Versions make it possible to tell license keys generated with
+    /// different schemes apart. See [`LicenseKey::version`].
+    ///
+    /// [`LicenseKey::version`]: struct.LicenseKey.html#method.version
+    pub fn include_version(&mut self, version: u8) {
+        self.version = Some(version);
+    }
+
+    /// Includes a flags byte right after the version in all generated
+    /// license keys. This is disabled by default.
+    ///
+    /// Trial license keys always contain the flags byte, so enabling it
+    /// gives paid license keys the same layout as trial license keys, which
+    /// makes it possible to verify both with the same layout. The flags
+    /// byte is mixed into the payload. See [`LicenseKey::is_trial`].
+    ///
+    /// [`LicenseKey::is_trial`]: struct.LicenseKey.html#method.is_trial
+    pub fn include_flags(&mut self, enabled: bool) {
+        self.flags = enabled;
+    }
+
+    /// Creates a new license key with the specified seed.
+    pub fn generate(&self, seed: u64) -> LicenseKey {
+        self.generate_with_fields(seed, KeyFields::default())
+    }
+
+    /// Creates a new license key with the specified seed and serializes
+    /// it by using the provided [`Serializer`].
+    ///
+    /// [`Serializer`]: trait.Serializer.html
+    pub fn generate_str<S: Serializer + ?Sized>(&self, seed: u64, serializer: &S) -> String {
+        self.generate(seed).serialize(serializer)
+    }
+
+    /// Creates a new license key for each of the specified seeds.
+    ///
+    /// This is the same as calling [`generate`] for every seed.
+    /// Use [`generate_iter`] to avoid keeping all license keys in memory.
+    ///
+    /// [`generate`]: struct.Generator.html#method.generate
+    /// [`generate_iter`]: struct.Generator.html#method.generate_iter
+    pub fn generate_batch<I: IntoIterator<Item = u64>>(&self, seeds: I) -> Vec<LicenseKey> {
+        self.generate_iter(seeds).collect()
+    }
+
+    /// Returns an iterator that lazily creates a new license
+    /// key for each of the specified seeds.
+    pub fn generate_iter<'a, I>(&'a self, seeds: I) -> impl Iterator<Item = LicenseKey> + 'a
+    where
+        I: IntoIterator<Item = u64>,
+        I::IntoIter: 'a,
+    {
+        seeds.into_iter().map(move |seed| self.generate(seed))
+    }
+
+    /// Creates a new license key with the specified 128-bit seed.
+    /// See [`Generator::generate_seed`].
+    ///
+    /// [`Generator::generate_seed`]: struct.Generator.html#method.generate_seed
+    pub fn generate_wide(&self, seed: u128) -> LicenseKey {
+        self.generate_seed(seed)
+    }
+
+    /// Creates a new license key with a seed of any [`Seed`] type,
+    /// such as `u32` for shorter license keys or `u128` for more seeds.
+    ///
+    /// The full seed is stored in the license key, and a seed that isn't
+    /// 64 bits long is folded into a 64-bit seed by the hasher before the
+    /// payload is calculated. See [`KeyHasher::fold_seed`]. License keys
+    /// with a seed that isn't 64 bits long must be parsed with a
+    /// [`KeyLayout`] where `seed_length` is set to the length of the seed type.
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`KeyHasher::fold_seed`]: trait.KeyHasher.html#method.fold_seed
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    pub fn generate_seed<S: Seed>(&self, seed: S) -> LicenseKey {
+        let bytes = seed.to_bytes();
+        self.generate_with_fields(
+            S::LENGTH.hash_seed(&self.hasher, &bytes),
+            KeyFields {
+                seed: Some((S::LENGTH, bytes)),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed and verifies it
+    /// with the provided verifier before returning it.
+    ///
+    /// This catches generators and verifiers that have drifted apart
+    /// when the license key is issued rather than when it's used.
+    /// If the license key isn't valid, a [`GenError`] is returned.
+    ///
+    /// [`GenError`]: enum.GenError.html
+    pub fn generate_checked<B: BlockList>(
+        &self,
+        seed: u64,
+        verifier: &Verifier<T, B>,
+    ) -> Result<LicenseKey, GenError> {
+        let key = self.generate(seed);
+        match verifier.verify(&key) {
+            status if status.is_valid() => Ok(key),
+            status => Err(GenError::Verification(status)),
+        }
+    }
+
+    /// Creates a new license key with the specified seed for a specific device.
+    ///
+    /// All device keys for a seed share the same seed, but the device index
+    /// is mixed into the payload so that every device gets a distinct key.
+    /// The device index is stored in the key and can be retrieved
+    /// using [`LicenseKey::device_index`].
+    ///
+    /// [`LicenseKey::device_index`]: struct.LicenseKey.html#method.device_index
+    pub fn generate_device_key(&self, seed: u64, device_index: u32) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                device_index: Some(device_index),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed that is
+    /// only valid within the specified region.
+    ///
+    /// The region code is mixed into the payload, which means that it
+    /// can't be altered without the byte checks failing. The region code
+    /// can be retrieved using [`LicenseKey::region`], and is verified
+    /// using [`Verifier::verify_for_region`].
+    ///
+    /// [`LicenseKey::region`]: struct.LicenseKey.html#method.region
+    /// [`Verifier::verify_for_region`]: struct.Verifier.html#method.verify_for_region
+    pub fn generate_region_key(&self, seed: u64, region: u16) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                region: Some(region),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed that
+    /// expires at the specified time (as a Unix timestamp).
+    ///
+    /// The expiration time is mixed into the payload, which means that
+    /// it can't be altered without the byte checks failing. Verify the
+    /// license key using [`Verifier::verify_at`], or with a verifier that
+    /// has a clock set using [`Verifier::use_clock`].
+    ///
+    /// [`Verifier::verify_at`]: struct.Verifier.html#method.verify_at
+    /// [`Verifier::use_clock`]: struct.Verifier.html#method.use_clock
+    pub fn generate_with_expiry(&self, seed: u64, expires_at: u32) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                expires_at: Some(expires_at),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new trial license key with the specified seed that
+    /// expires at the specified time (as a Unix timestamp).
+    ///
+    /// Instead of [`Status::Valid`], a trial license key verifies as
+    /// [`Status::Trial`] with the number of days remaining until it expires,
+    /// and as [`Status::Expired`] once it has expired or if the verifier
+    /// doesn't know the current time. The trial flag is
+    /// stored in the flags byte of the license key and mixed into the
+    /// payload, so a trial license key can't be turned into a paid one.
+    /// Trial license keys must be parsed with a [`KeyLayout`] where `expiry`
+    /// and `flags` are set. Use [`Generator::include_flags`] to give paid
+    /// license keys the same layout.
+    ///
+    /// [`Status::Valid`]: enum.Status.html#variant.Valid
+    /// [`Status::Trial`]: enum.Status.html#variant.Trial
+    /// [`Status::Expired`]: enum.Status.html#variant.Expired
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`Generator::include_flags`]: struct.Generator.html#method.include_flags
+    pub fn generate_trial(&self, seed: u64, expires_at: u32) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                expires_at: Some(expires_at),
+                trial: true,
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed that records
+    /// the date it was issued at (as a Unix timestamp).
+    ///
+    /// Only the day is stored, as the number of days since the Unix epoch,
+    /// which takes two bytes. The issue date is purely informational and
+    /// doesn't affect whether or not the license key is valid, but it's
+    /// mixed into the payload, which means that it can't be altered without
+    /// the byte checks failing. The issue date can be retrieved using
+    /// [`LicenseKey::issued_at`].
+    ///
+    /// [`LicenseKey::issued_at`]: struct.LicenseKey.html#method.issued_at
+    pub fn generate_with_issue_date(&self, seed: u64, issued_at: u32) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                issue_date: Some((issued_at / SECONDS_PER_DAY) as u16),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed that
+    /// unlocks the features in the specified bitmask.
+    ///
+    /// The bitmask is mixed into the payload, which means that it can't be
+    /// altered without the byte checks failing. The checksum only catches
+    /// accidental changes, so a bitmask that has been altered on purpose
+    /// (with the checksum recalculated) is reported as [`Status::Forged`].
+    /// The bitmask can be retrieved using [`LicenseKey::features`].
+    ///
+    /// [`Status::Forged`]: enum.Status.html#variant.Forged
+    /// [`LicenseKey::features`]: struct.LicenseKey.html#method.features
+    pub fn generate_with_features(&self, seed: u64, features: u32) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                features: Some(features),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Creates a new license key with the specified seed that is
+    /// bound to the machine with the specified identifier.
+    ///
+    /// The machine identifier is mixed into the payload, but it isn't
+    /// stored in the license key, so it can't be read out of it. License
+    /// keys that are bound to a machine must be parsed with a [`KeyLayout`]
+    /// where `machine` is set, and verified with a verifier that knows the
+    /// identifier of the current machine. See [`Verifier::use_machine_id`].
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`Verifier::use_machine_id`]: struct.Verifier.html#method.use_machine_id
+    pub fn generate_for_machine(&self, seed: u64, machine_id: u64) -> LicenseKey {
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                machine_id: Some(machine_id),
+                ..KeyFields::default()
+            },
+        )
+    }
+
+    /// Gets the number of segments in the payload of generated license
+    /// keys, including decoy bytes and padding.
+    ///
+    /// Use this as the expected payload length of a verifier.
+    /// See [`Verifier::try_new`].
+    ///
+    /// [`Verifier::try_new`]: struct.Verifier.html#method.try_new
+    pub fn payload_length(&self) -> usize {
+        (self.iv.len() + self.decoys.len()).max(self.padded_length)
+    }
+
+    /// Gets the payload that a license key
+    /// generated with the specified seed would have.
+    pub fn expected_payload(&self, seed: u64) -> Vec<u8> {
+        self.payload(seed)
+    }
+
+    /// Adds a decoy byte at the specified payload ordinal.
+    ///
+    /// Decoy bytes are derived from the seed and the provided triplet
+    /// without using the hasher, which means that anyone who has figured
+    /// out how the hasher and initialization vector work will still produce
+    /// the wrong decoy bytes. Use [`ByteCheck::decoy`] to check decoy bytes.
+    ///
+    /// Decoy bytes are inserted into the payload, so the bytes derived from
+    /// the initialization vector that follow will be moved one position.
+    /// Decoys with an ordinal outside of the payload are added at the end.
+    ///
+    /// [`ByteCheck::decoy`]: struct.ByteCheck.html#method.decoy
+    pub fn add_decoy(&mut self, ordinal: u8, iv: (u64, u64, u64)) {
+        self.decoys.push((ordinal, iv));
+        self.decoys.sort_by_key(|(ordinal, _)| *ordinal);
+    }
+
+    fn payload(&self, seed: u64) -> Vec<u8> {
+        let length = self.iv.len() + self.decoys.len();
+        let segment_length = self.segment_length.bytes();
+        let mut payload = Vec::with_capacity(length.max(self.padded_length) * segment_length);
+        let mut segment = [0; 4];
+        let segment = &mut segment[..segment_length];
+        let mut ivs = self.iv.iter();
+        let mut decoys = self.decoys.iter().peekable();
+        for position in 0..length {
+            match decoys.peek() {
+                Some((ordinal, iv)) if *ordinal as usize <= position || ivs.len() == 0 => {
+                    decoys.next();
+                    decoy_segment(seed, iv.0, iv.1, iv.2, segment);
+                }
+                _ => {
+                    let iv = ivs.next().unwrap();
+                    self.hasher.hash_segment(seed, iv.0, iv.1, iv.2, segment);
+                }
+            };
+            payload.extend_from_slice(segment);
+        }
+
+        // Pad the payload to the requested length
+        for position in length..self.padded_length {
+            self.hasher
+                .hash_segment(seed, position as u64, length as u64, !0, segment);
+            payload.extend_from_slice(segment);
+        }
+
+        payload
+    }
+
+    pub(crate) fn generate_with_fields(&self, seed: u64, fields: KeyFields) -> LicenseKey {
+        let flags = match (fields.trial, self.flags) {
+            (true, _) => Some(TRIAL_FLAG),
+            (false, true) => Some(0),
+            (false, false) => None,
+        };
+        let layout = KeyLayout {
+            seed_length: fields
+                .seed
+                .as_ref()
+                .map_or(SeedLength::Eight, |(seed_length, _)| *seed_length),
+            seed_checksum: self.seed_checksum,
+            version: self.version.is_some(),
+            flags: flags.is_some(),
+            device_index: fields.device_index.is_some(),
+            region: fields.region.is_some(),
+            expiry: fields.expires_at.is_some(),
+            issue_date: fields.issue_date.is_some(),
+            features: fields.features.is_some(),
+            machine: fields.machine_id.is_some(),
+            claims: fields.claims.is_some(),
+            long_checksum: self.long_checksum,
+            segment_length: self.segment_length,
+        };
+
+        // Get the license key as a byte array
+        let mut input = match fields.seed {
+            Some((_, bytes)) => bytes,
+            None => seed.to_be_bytes().to_vec(),
+        };
+        if self.seed_checksum {
+            input.push(calculate_seed_checksum(&input));
+        }
+        if let Some(version) = self.version {
+            input.push(version);
+        }
+        if let Some(flags) = flags {
+            input.push(flags);
+        }
+        let mut hash_seed = seed;
+        if let Some(device_index) = fields.device_index {
+            input.extend_from_slice(&device_index.to_be_bytes());
+            hash_seed = device_seed(seed, device_index);
+        }
+        if let Some(region) = fields.region {
+            input.extend_from_slice(&region.to_be_bytes());
+            hash_seed = region_seed(hash_seed, region);
+        }
+        if let Some(expires_at) = fields.expires_at {
+            input.extend_from_slice(&expires_at.to_be_bytes());
+            hash_seed = expiry_seed(hash_seed, expires_at);
+        }
+        if let Some(issue_date) = fields.issue_date {
+            input.extend_from_slice(&issue_date.to_be_bytes());
+            hash_seed = issue_date_seed(hash_seed, issue_date);
+        }
+        if let Some(features) = fields.features {
+            input.extend_from_slice(&features.to_be_bytes());
+            hash_seed = features_seed(hash_seed, features);
+        }
+        if let Some(flags) = flags {
+            hash_seed = flags_seed(hash_seed, flags);
+        }
+        if let Some(machine_id) = fields.machine_id {
+            // The machine identifier isn't stored, only mixed into the payload
+            hash_seed = machine_seed(hash_seed, machine_id);
+        }
+        if let Some((claims, signature)) = fields.claims {
+            input.extend_from_slice(&claims.to_bytes());
+            input.extend_from_slice(&signature);
+        }
+
+        input.extend(self.payload(hash_seed));
+
+        // Calculate the checksum for the license key
+        let checksum = compute_checksum(
+            self.checksum.as_deref(),
+            &input,
+            self.fold_length,
+            self.long_checksum,
+        );
+        for byte in checksum.iter() {
+            input.push(*byte);
+        }
+
+        // A custom checksum decides the length of the checksum on its own
+        let layout = KeyLayout {
+            long_checksum: checksum.len() == LONG_CHECKSUM_BYTE_LENGTH as usize,
+            ..layout
+        };
+        LicenseKey::new(input, layout)
+    }
+}
+
+/// Counts how many of the provided seeds result in identical payloads
+/// for the two generators.
+///
+/// This is useful when migrating to a new hasher to make sure that the
+/// new hasher actually produces different license keys than the old one,
+/// in which case the result should be (close to) zero.
+pub fn count_identical_payloads<A: KeyHasher, B: KeyHasher>(
+    first: &Generator<A>,
+    second: &Generator<B>,
+    seeds: impl IntoIterator<Item = u64>,
+) -> usize {
+    seeds
+        .into_iter()
+        .filter(|seed| first.expected_payload(*seed) == second.expected_payload(*seed))
+        .count()
+}
+
+/// Estimates the probability that at least two of `n_customers` share
+/// the same seed when seeds are picked from a keyspace of `keyspace_bits` bits.
+///
+/// This uses the birthday approximation `1 - e^(-n(n-1) / 2d)`, where
+/// `d` is the size of the keyspace, and can be used to decide whether
+/// the seed is wide enough for the expected number of customers.
+#[cfg(feature = "std")]
+pub fn birthday_collision_probability(keyspace_bits: u32, n_customers: u64) -> f64 {
+    let keyspace = 2_f64.powi(keyspace_bits as i32);
+    let n = n_customers as f64;
+    let exponent = -(n * (n - 1.0)) / (2.0 * keyspace);
+    -exponent.exp_m1()
+}
+
+/// An error that occurs when creating a generator or a license key.
+#[derive(Debug, PartialEq)]
+pub enum GenError {
+    /// The generated license key was rejected by the verifier
+    /// with the specified status. See [`Generator::generate_checked`].
+    ///
+    /// [`Generator::generate_checked`]: struct.Generator.html#method.generate_checked
+    Verification(Status),
+    /// The initialization vector contains a published example
+    /// triplet. See [`Generator::try_new`].
+    ///
+    /// [`Generator::try_new`]: struct.Generator.html#method.try_new
+    PublishedIv,
+    /// The initialization vector is empty. See [`Generator::try_new`].
+    ///
+    /// [`Generator::try_new`]: struct.Generator.html#method.try_new
+    EmptyIv,
+}
+
+impl core::fmt::Display for GenError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GenError::Verification(status) => {
+                write!(f, "generated license key was {:?}", status)
+            }
+            GenError::PublishedIv => {
+                write!(f, "initialization vector contains a published example")
+            }
+            GenError::EmptyIv => write!(f, "initialization vector is empty"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GenError {}
+
+/// An error that occurs when creating a verifier.
+#[derive(Debug, PartialEq)]
+pub enum VerifierError {
+    /// A byte check has an ordinal outside of the payload, which means
+    /// that no license key could ever pass it. See [`Verifier::try_new`].
+    ///
+    /// [`Verifier::try_new`]: struct.Verifier.html#method.try_new
+    OrdinalOutOfRange {
+        /// The ordinal of the byte check.
+        ordinal: u8,
+        /// The expected payload length.
+        payload_length: usize,
+    },
+}
+
+impl core::fmt::Display for VerifierError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VerifierError::OrdinalOutOfRange {
+                ordinal,
+                payload_length,
+            } => write!(
+                f,
+                "byte check ordinal {} is outside of the payload of length {}",
+                ordinal, payload_length
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifierError {}
+
+/// An error that occurs when a license key isn't valid.
+/// See [`Verifier::check`].
+///
+/// Every variant mirrors a [`Status`] other than [`Status::Valid`].
+///
+/// [`Verifier::check`]: struct.Verifier.html#method.check
+/// [`Status`]: enum.Status.html
+/// [`Status::Valid`]: enum.Status.html#variant.Valid
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum LicenseError {
+    /// The license is invalid.
+    Invalid,
+    /// The license has been blocked.
+    Blocked,
+    /// The license has been forged.
+    Forged,
+    /// There have been too many failed verification attempts for the license.
+    RateLimited,
+    /// The license has expired.
+    Expired,
+    /// The license isn't valid in the region it's used in.
+    WrongRegion,
+    /// The license has a version that no verifier has been registered for.
+    UnsupportedVersion,
+    /// The license is bound to another machine.
+    WrongMachine,
+}
+
+impl LicenseError {
+    pub(crate) fn check(status: Status) -> Result<(), LicenseError> {
+        match status {
+            Status::Valid | Status::Trial { .. } => Ok(()),
+            Status::Invalid => Err(LicenseError::Invalid),
+            Status::Blocked => Err(LicenseError::Blocked),
+            Status::Forged => Err(LicenseError::Forged),
+            Status::RateLimited => Err(LicenseError::RateLimited),
+            Status::Expired => Err(LicenseError::Expired),
+            Status::WrongRegion => Err(LicenseError::WrongRegion),
+            Status::UnsupportedVersion => Err(LicenseError::UnsupportedVersion),
+            Status::WrongMachine => Err(LicenseError::WrongMachine),
+        }
+    }
+}
+
+impl core::fmt::Display for LicenseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LicenseError::Invalid => write!(f, "the license key is invalid"),
+            LicenseError::Blocked => write!(f, "the license key has been blocked"),
+            LicenseError::Forged => write!(f, "the license key has been forged"),
+            LicenseError::RateLimited => {
+                write!(f, "too many failed verification attempts")
+            }
+            LicenseError::Expired => write!(f, "the license key has expired"),
+            LicenseError::WrongRegion => {
+                write!(f, "the license key isn't valid in this region")
+            }
+            LicenseError::UnsupportedVersion => {
+                write!(f, "the license key version isn't supported")
+            }
+            LicenseError::WrongMachine => {
+                write!(f, "the license key is bound to another machine")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LicenseError {}
+
+/// Creates a deterministic seed from a counter and a secret.
+///
+/// The counter is run through a keyed permutation, which means that every
+/// counter results in a unique seed, without the seeds revealing the order
+/// in which they were issued. The same counter and secret always result
+/// in the same seed.
+pub fn sequence_seed(counter: u64, secret: &[u8]) -> u64 {
+    // Feistel network with 32-bit halves
+    let mut left = (counter >> 32) as u32;
+    let mut right = counter as u32;
+    for round in 0..SEQUENCE_SEED_ROUNDS {
+        let mixed = mix_round(right, sequence_round_key(secret, round));
+        let next = left ^ mixed;
+        left = right;
+        right = next;
+    }
+    ((left as u64) << 32) | right as u64
+}
+
+/// Creates a seed from the provided string, such as an e-mail address.
+///
+/// See [`seed_from_bytes`].
+///
+/// [`seed_from_bytes`]: fn.seed_from_bytes.html
+pub fn seed_from_str(input: &str) -> u64 {
+    seed_from_bytes(input.as_bytes())
+}
+
+/// Creates a seed from the provided bytes by hashing them with 64-bit FNV-1a.
+///
+/// The same input always results in the same seed, on every platform.
+/// Since existing license keys depend on it, this hash will never change.
+/// Note that no normalization is performed, so `Foo@example.com` and
+/// `foo@example.com` result in different seeds.
+pub fn seed_from_bytes(input: &[u8]) -> u64 {
+    let mut hash = 0xCBF2_9CE4_8422_2325_u64;
+    for byte in input.iter() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    hash
+}
+
+/// Representation of a license key status.
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Status {
+    /// The license is valid.
+    Valid,
+    /// The license is invalid.
+    Invalid,
+    /// The license has been blocked.
+    Blocked,
+    /// The license has been forged.
+    Forged,
+    /// There have been too many failed verification
+    /// attempts for the license. See [`RateLimiter`].
+    ///
+    /// [`RateLimiter`]: struct.RateLimiter.html
+    RateLimited,
+    /// The license has expired. See [`Verifier::decide`].
+    ///
+    /// [`Verifier::decide`]: struct.Verifier.html#method.decide
+    Expired,
+    /// The license is not valid in the current region.
+    /// See [`Verifier::verify_for_region`].
+    ///
+    /// [`Verifier::verify_for_region`]: struct.Verifier.html#method.verify_for_region
+    WrongRegion,
+    /// The license has a version that no verifier has been
+    /// registered for. See [`VersionedVerifier`].
+    ///
+    /// [`VersionedVerifier`]: struct.VersionedVerifier.html
+    UnsupportedVersion,
+    /// The license is bound to another machine.
+    /// See [`Verifier::use_machine_id`].
+    ///
+    /// [`Verifier::use_machine_id`]: struct.Verifier.html#method.use_machine_id
+    WrongMachine,
+    /// The license is a trial that hasn't expired yet.
+    /// See [`Generator::generate_trial`].
+    ///
+    /// Trials are only reported when the current time is known, either
+    /// from [`Verifier::verify_at`] or a clock set with [`Verifier::use_clock`].
+    /// Without it, a trial license verifies as [`Status::Expired`].
+    ///
+    /// [`Verifier::verify_at`]: struct.Verifier.html#method.verify_at
+    /// [`Verifier::use_clock`]: struct.Verifier.html#method.use_clock
+    /// [`Status::Expired`]: enum.Status.html#variant.Expired
+    /// [`Generator::generate_trial`]: struct.Generator.html#method.generate_trial
+    Trial {
+        /// The number of days until the trial expires, rounded up.
+        days_remaining: u32,
+    },
+}
+
+impl Status {
+    /// Checks whether or not the license may be used, which is
+    /// the case for valid licenses and trials that haven't expired.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Status::Valid | Status::Trial { .. })
+    }
+
+    /// Gets a process exit code for the status, which is
+    /// `0` for a valid license and a distinct non-zero code otherwise.
+    ///
+    /// The codes are stable, and new statuses get new codes.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Status::Valid => 0,
+            Status::Invalid => 1,
+            Status::Blocked => 2,
+            Status::Forged => 3,
+            Status::RateLimited => 4,
+            Status::Expired => 5,
+            Status::WrongRegion => 6,
+            Status::UnsupportedVersion => 7,
+            Status::WrongMachine => 8,
+            Status::Trial { .. } => 9,
+        }
+    }
+}
+
+impl core::fmt::Display for Status {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Status::Valid => write!(f, "the license key is valid"),
+            Status::Invalid => write!(f, "the license key is invalid"),
+            Status::Blocked => write!(f, "the license key has been blocked"),
+            Status::Forged => write!(f, "the license key has been forged"),
+            Status::RateLimited => write!(f, "too many failed verification attempts"),
+            Status::Expired => write!(f, "the license key has expired"),
+            Status::WrongRegion => write!(f, "the license key isn't valid in this region"),
+            Status::UnsupportedVersion => write!(f, "the license key version isn't supported"),
+            Status::WrongMachine => write!(f, "the license key is bound to another machine"),
+            Status::Trial { days_remaining } => {
+                write!(f, "the trial has {} days remaining", days_remaining)
+            }
+        }
+    }
+}
+
+/// The reason why a license key didn't verify as valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Reason {
+    /// The license key doesn't have the expected length.
+    Length,
+    /// The license key checksum doesn't match.
+    Checksum,
+    /// The seed of the license key has been blocked.
+    BlockedSeed,
+    /// The seed of the license key is within a blocked range.
+    BlockedRange,
+    /// The seed of the license key starts with a blocked prefix.
+    BlockedPrefix,
+    /// The device of the license key has been blocked.
+    BlockedDevice,
+    /// A checked byte is not part of the license key.
+    MissingByte,
+    /// A checked byte doesn't have the expected value.
+    ByteCheck,
+    /// The claims of the license key have expired.
+    Expired,
+    /// The license key is bound to a machine, and the verifier either
+    /// doesn't know the current machine or the byte checks didn't match.
+    WrongMachine,
+}
+
+/// The outcome of a license key verification, suitable
+/// for returning from an API.
+///
+/// When the `serde` feature is enabled, the outcome can be serialized
+/// into something like `{ "status": "invalid", "reason": "checksum" }`.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VerificationOutcome {
+    /// The status of the license key.
+    pub status: Status,
+    /// The reason for the status, if the license key isn't valid.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reason: Option<Reason>,
+    /// The claims embedded in the license key, if the license key is valid.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub claims: Option<Claims>,
+    /// The first byte check that didn't match, if the license key is forged.
+    ///
+    /// When a subset of byte checks is used, only the byte checks that
+    /// were run for this verification are considered.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub mismatch: Option<Mismatch>,
+    /// The blocklist entry that matched, if the license key is blocked.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub blocked: Option<BlockMatch>,
+}
+
+/// A blocklist entry that matched a license key. See [`VerificationOutcome`].
+///
+/// [`VerificationOutcome`]: struct.VerificationOutcome.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BlockMatch {
+    /// The entry that matched.
+    pub entry: BlockEntry,
+    /// The reason that the seed was blocked with, if any.
+    /// See [`Verifier::block_with_reason`].
+    ///
+    /// [`Verifier::block_with_reason`]: struct.Verifier.html#method.block_with_reason
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reason: Option<String>,
+}
+
+/// A byte check that didn't match. See [`VerificationOutcome`].
+///
+/// [`VerificationOutcome`]: struct.VerificationOutcome.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Mismatch {
+    /// The ordinal of the byte check.
+    pub ordinal: u8,
+    /// The segment that the byte check expected, as a big-endian number.
+    ///
+    /// This is only included if [`Verifier::reveal_expected_segments`]
+    /// has been enabled, since the expected segment is all a forger
+    /// needs to turn a forged license key into a valid one.
+    ///
+    /// [`Verifier::reveal_expected_segments`]: struct.Verifier.html#method.reveal_expected_segments
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub expected: Option<u32>,
+    /// The segment in the license key, as a big-endian number.
+    pub actual: u32,
+}
+
+/// A license key that has been decoded, but not yet decided upon.
+/// See [`Verifier::decode`].
+///
+/// [`Verifier::decode`]: struct.Verifier.html#method.decode
+#[derive(Debug)]
+pub struct DecodedKey<'a> {
+    /// The seed of the license key, widened to 128 bits.
+    /// See [`LicenseKey::wide_seed`].
+    ///
+    /// [`LicenseKey::wide_seed`]: struct.LicenseKey.html#method.wide_seed
+    pub seed: u128,
+    /// The device index of the license key, if any.
+    pub device_index: Option<u32>,
+    /// The time when the license key expires, if any.
+    pub expires_at: Option<u32>,
+    /// The claims embedded in the license key, if any.
+    /// Note that the claims have not been authenticated.
+    pub claims: Option<Claims>,
+    key: &'a LicenseKey,
+}
+
+/// Represents an entry in the blocklist of a [`Verifier`].
+///
+/// [`Verifier`]: struct.Verifier.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum BlockEntry {
+    /// A single blocked seed.
+    Seed(u64),
+    /// A range of blocked seeds (inclusive).
+    Range(u64, u64),
+    /// A blocked seed prefix and the mask of the bits it covers.
+    Prefix(u64, u64),
+    /// A single blocked device for a seed.
+    Device(u64, u32),
+    /// A single blocked 32-bit seed. See [`Verifier::block_seed`].
+    ///
+    /// [`Verifier::block_seed`]: struct.Verifier.html#method.block_seed
+    NarrowSeed(u32),
+    /// A single blocked 128-bit seed. See [`Verifier::block_wide`].
+    ///
+    /// [`Verifier::block_wide`]: struct.Verifier.html#method.block_wide
+    WideSeed(u128),
+}
+
+/// The result of unblocking a seed. See [`Verifier::unblock`].
+///
+/// [`Verifier::unblock`]: struct.Verifier.html#method.unblock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnblockResult {
+    /// The seed was blocked and has been unblocked.
+    Unblocked,
+    /// The seed wasn't blocked.
+    NotBlocked,
+    /// The seed is blocked by the specified range (inclusive)
+    /// and can't be unblocked individually.
+    BlockedByRange(u64, u64),
+    /// The seed is blocked by the specified prefix and mask
+    /// and can't be unblocked individually.
+    BlockedByPrefix(u64, u64),
+}
+
+/// Represents a license key byte check
+/// that should be used during validation.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ByteCheck {
+    pub ordinal: u8,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    /// Whether or not the check targets a decoy byte.
+    /// See [`Generator::add_decoy`].
+    ///
+    /// [`Generator::add_decoy`]: struct.Generator.html#method.add_decoy
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub decoy: bool,
+}
+
+impl ByteCheck {
+    /// Creates a new byte check.
+    pub fn new(ordinal: u8, iv: (u64, u64, u64)) -> Self {
+        Self {
+            ordinal,
+            a: iv.0,
+            b: iv.1,
+            c: iv.2,
+            decoy: false,
+        }
+    }
+
+    /// Creates a new byte check for a decoy byte.
+    /// See [`Generator::add_decoy`].
+    ///
+    /// [`Generator::add_decoy`]: struct.Generator.html#method.add_decoy
+    pub fn decoy(ordinal: u8, iv: (u64, u64, u64)) -> Self {
+        Self {
+            decoy: true,
+            ..Self::new(ordinal, iv)
+        }
+    }
+
+    pub(crate) fn expected<T: KeyHasher>(
+        &self,
+        hasher: &T,
+        seed: u64,
+        segment_length: SegmentLength,
+    ) -> u32 {
+        let mut segment = [0; 4];
+        let segment = &mut segment[..segment_length.bytes()];
+        if self.decoy {
+            decoy_segment(seed, self.a, self.b, self.c, segment);
+        } else {
+            hasher.hash_segment(seed, self.a, self.b, self.c, segment);
+        }
+        segment_value(segment)
+    }
+}
+
+/// The license key verifier.
+///
+/// Blocked seeds are kept in a [`MemoryBlockList`] by default. Use
+/// [`Verifier::with_blocklist`] to consult another [`BlockList`] instead.
+///
+/// [`MemoryBlockList`]: struct.MemoryBlockList.html
+/// [`Verifier::with_blocklist`]: struct.Verifier.html#method.with_blocklist
+/// [`BlockList`]: trait.BlockList.html
+#[derive(Debug)]
+pub struct Verifier<T: KeyHasher, B: BlockList = MemoryBlockList> {
+    hasher: T,
+    previous_hashers: Vec<T>,
+    checks: Vec<ByteCheck>,
+    check_subset: Option<(usize, Box<dyn RandomSource>)>,
+    blocklist: B,
+    block_reasons: Map<u64, String>,
+    blocked_ranges: Vec<(u64, u64)>,
+    blocked_prefixes: Vec<(u64, u64)>,
+    blocked_devices: Vec<(u64, u32)>,
+    blocked_narrow_seeds: Set<u32>,
+    blocked_wide_seeds: Set<u128>,
+    tolerate_single_mismatch: bool,
+    payload_length: Option<usize>,
+    conceal_blocked: bool,
+    reveal_expected: bool,
+    fold_length: bool,
+    iv: Option<Vec<(u64, u64, u64)>>,
+    layouts: Option<LayoutRegistry>,
+    clock: Option<Box<dyn Clock>>,
+    checksum: Option<Box<dyn Checksum>>,
+    machine_id: Option<u64>,
+}
+
+impl<T: KeyHasher> Verifier<T> {
+    /// Creates a new license key verifier.
+    pub fn new(hasher: T, checks: Vec<ByteCheck>) -> Self {
+        Verifier::with_blocklist(hasher, checks, MemoryBlockList::default())
+    }
+
+    /// Creates a new license key verifier that expects license keys with
+    /// the specified payload length, unless any of the byte checks has an
+    /// ordinal outside of the payload. See [`expect_payload_length`].
+    ///
+    /// Use [`Generator::payload_length`] to get the payload length of the
+    /// license keys that a generator creates.
+    ///
+    /// [`expect_payload_length`]: struct.Verifier.html#method.expect_payload_length
+    /// [`Generator::payload_length`]: struct.Generator.html#method.payload_length
+    pub fn try_new(
+        hasher: T,
+        checks: Vec<ByteCheck>,
+        payload_length: usize,
+    ) -> Result<Self, VerifierError> {
+        let mut verifier = Verifier::new(hasher, checks);
+        verifier.expect_payload_length(payload_length);
+        verifier.validate_ordinals()?;
+        Ok(verifier)
+    }
+
+    /// Blocks the specified seed from being used.
+    ///
+    /// Only license keys with a 64-bit seed are blocked. Use
+    /// [`Verifier::block_seed`] to block a seed of another length.
+    ///
+    /// [`Verifier::block_seed`]: struct.Verifier.html#method.block_seed
+    pub fn block(&mut self, seed: u64) {
+        self.blocklist.insert(seed);
+    }
+
+    /// Blocks the specified seed of any [`Seed`] type from being used.
+    ///
+    /// Seeds are only matched against license keys with a seed of the
+    /// same length, which means that blocking the 32-bit seed `5` doesn't
+    /// block license keys with the 64-bit seed `5`, and vice versa.
+    /// 64-bit seeds are blocked just like with [`Verifier::block`].
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`Verifier::block`]: struct.Verifier.html#method.block
+    pub fn block_seed<S: Seed>(&mut self, seed: S) {
+        let bytes = seed.to_bytes();
+        match S::LENGTH {
+            SeedLength::Four => self.blocked_narrow_seeds.extend(u32::from_bytes(&bytes)),
+            SeedLength::Eight => self.blocklist.extend(u64::from_bytes(&bytes)),
+            SeedLength::Sixteen => self.blocked_wide_seeds.extend(u128::from_bytes(&bytes)),
+        }
+    }
+
+    /// Unblocks the specified seed of any [`Seed`] type and returns whether
+    /// or not the seed was blocked in the first place. 64-bit seeds are
+    /// unblocked just like with [`Verifier::unblock`].
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`Verifier::unblock`]: struct.Verifier.html#method.unblock
+    pub fn unblock_seed<S: Seed>(&mut self, seed: S) -> UnblockResult {
+        let bytes = seed.to_bytes();
+        let removed = match S::LENGTH {
+            SeedLength::Four => match u32::from_bytes(&bytes) {
+                Some(seed) => self.blocked_narrow_seeds.remove(&seed),
+                None => false,
+            },
+            SeedLength::Eight => match u64::from_bytes(&bytes) {
+                Some(seed) => return self.unblock(seed),
+                None => false,
+            },
+            SeedLength::Sixteen => match u128::from_bytes(&bytes) {
+                Some(seed) => self.blocked_wide_seeds.remove(&seed),
+                None => false,
+            },
+        };
+        if removed {
+            UnblockResult::Unblocked
+        } else {
+            UnblockResult::NotBlocked
+        }
+    }
+
+    /// Blocks the specified seed from being used, and remembers
+    /// why it was blocked. See [`Verifier::block_reason`].
+    ///
+    /// [`Verifier::block_reason`]: struct.Verifier.html#method.block_reason
+    pub fn block_with_reason(&mut self, seed: u64, reason: String) {
+        self.blocklist.insert(seed);
+        self.block_reasons.insert(seed, reason);
+    }
+
+    /// Unblocks the specified seed and returns whether or not
+    /// the seed was blocked in the first place.
+    ///
+    /// Seeds that are blocked by a range or a prefix can't be unblocked
+    /// individually. If the seed is within a blocked range,
+    /// [`UnblockResult::BlockedByRange`] is returned, and likewise
+    /// [`UnblockResult::BlockedByPrefix`] for a prefix. In that case nothing
+    /// is changed, so any individual block and its reason are kept for when
+    /// the range or prefix is lifted.
+    ///
+    /// [`UnblockResult::BlockedByRange`]: enum.UnblockResult.html#variant.BlockedByRange
+    /// [`UnblockResult::BlockedByPrefix`]: enum.UnblockResult.html#variant.BlockedByPrefix
+    pub fn unblock(&mut self, seed: u64) -> UnblockResult {
+        for (start, end) in self.blocked_ranges.iter() {
+            if seed >= *start && seed <= *end {
+                return UnblockResult::BlockedByRange(*start, *end);
+            }
+        }
+
+        for (prefix, mask) in self.blocked_prefixes.iter() {
+            if seed & mask == *prefix {
+                return UnblockResult::BlockedByPrefix(*prefix, *mask);
+            }
+        }
+
+        let unblocked = self.blocklist.remove(seed);
+        self.block_reasons.remove(&seed);
+        if unblocked {
+            UnblockResult::Unblocked
+        } else {
+            UnblockResult::NotBlocked
+        }
+    }
+
+    /// Removes all blocked seeds for which the provided predicate returns `false`.
+    ///
+    /// This is useful for cleaning up the blocklist, for example by
+    /// dropping seeds that belong to accounts that no longer exist.
+    pub fn prune_blocklist(&mut self, retain: impl Fn(u64) -> bool) {
+        self.blocklist.retain(&retain);
+        self.block_reasons.retain(|seed, _| retain(*seed));
+    }
+
+    /// Gets all individually blocked seeds in ascending order.
+    ///
+    /// Seeds that are blocked by a range are not included.
+    /// Use [`Verifier::blocked_entries`] to get the blocked ranges as well.
+    ///
+    /// [`Verifier::blocked_entries`]: struct.Verifier.html#method.blocked_entries
+    pub fn blocked_seeds(&self) -> impl Iterator<Item = u64> {
+        let mut seeds: Vec<u64> = self.blocklist.iter().collect();
+        seeds.sort_unstable();
+        seeds.into_iter()
+    }
+
+    /// Exports all individually blocked seeds in ascending order,
+    /// so that the blocklist can be persisted.
+    ///
+    /// Use [`Verifier::import_blocklist`] to restore the blocklist.
+    ///
+    /// [`Verifier::import_blocklist`]: struct.Verifier.html#method.import_blocklist
+    pub fn export_blocklist(&self) -> Vec<u64> {
+        self.blocked_seeds().collect()
+    }
+
+    /// Imports blocked seeds, for example ones that were exported
+    /// using [`Verifier::export_blocklist`].
+    ///
+    /// The seeds are merged with the seeds that are already blocked.
+    ///
+    /// [`Verifier::export_blocklist`]: struct.Verifier.html#method.export_blocklist
+    pub fn import_blocklist(&mut self, seeds: impl IntoIterator<Item = u64>) {
+        self.blocklist.extend(seeds);
+    }
+
+    /// Gets all blocked seeds (in ascending order), ranges, prefixes,
+    /// devices, 32-bit seeds and 128-bit seeds (in ascending order).
+    pub fn blocked_entries(&self) -> impl Iterator<Item = BlockEntry> + '_ {
+        let seeds = self.blocked_seeds().map(BlockEntry::Seed);
+        let ranges = self
+            .blocked_ranges
+            .iter()
+            .map(|(start, end)| BlockEntry::Range(*start, *end));
+        let prefixes = self
+            .blocked_prefixes
+            .iter()
+            .map(|(prefix, mask)| BlockEntry::Prefix(*prefix, *mask));
+        let devices = self
+            .blocked_devices
+            .iter()
+            .map(|(seed, device_index)| BlockEntry::Device(*seed, *device_index));
+        let mut narrow_seeds: Vec<u32> = self.blocked_narrow_seeds.iter().copied().collect();
+        narrow_seeds.sort_unstable();
+        let narrow_seeds = narrow_seeds.into_iter().map(BlockEntry::NarrowSeed);
+        let mut wide_seeds: Vec<u128> = self.blocked_wide_seeds.iter().copied().collect();
+        wide_seeds.sort_unstable();
+        let wide_seeds = wide_seeds.into_iter().map(BlockEntry::WideSeed);
+        seeds
+            .chain(ranges)
+            .chain(prefixes)
+            .chain(devices)
+            .chain(narrow_seeds)
+            .chain(wide_seeds)
+    }
+}
+
+impl<T: KeyHasher, B: BlockList> Verifier<T, B> {
+    /// Creates a new license key verifier that consults
+    /// the specified [`BlockList`] for blocked seeds.
+    ///
+    /// [`BlockList`]: trait.BlockList.html
+    pub fn with_blocklist(hasher: T, checks: Vec<ByteCheck>, blocklist: B) -> Self {
+        Self {
+            hasher,
+            previous_hashers: Vec::new(),
+            checks,
+            check_subset: None,
+            blocklist,
+            block_reasons: Map::new(),
+            blocked_ranges: Vec::new(),
+            blocked_prefixes: Vec::new(),
+            blocked_devices: Vec::new(),
+            blocked_narrow_seeds: Set::new(),
+            blocked_wide_seeds: Set::new(),
+            tolerate_single_mismatch: false,
+            payload_length: None,
+            conceal_blocked: false,
+            reveal_expected: false,
+            fold_length: false,
+            iv: None,
+            layouts: None,
+            clock: None,
+            machine_id: None,
+            checksum: None,
+        }
+    }
+
+    /// Gets the blocklist that the verifier consults for blocked seeds.
+    pub fn blocklist(&self) -> &B {
+        &self.blocklist
+    }
+
+    /// Gets a mutable reference to the blocklist that
+    /// the verifier consults for blocked seeds.
+    pub fn blocklist_mut(&mut self) -> &mut B {
+        &mut self.blocklist
+    }
+
+    /// Also accepts license keys whose payload was generated with the
+    /// specified hasher, such as a hasher with a secret that is being
+    /// rotated out. Any number of previous hashers can be added.
+    ///
+    /// The byte checks are the same for every hasher. A license key is
+    /// valid if its bytes pass the checks for any of the hashers, and is
+    /// only forged if the checksum is correct but the bytes don't pass the
+    /// checks for any hasher. Blocked seeds and expiration times apply no
+    /// matter which hasher the license key was generated with.
+    pub fn accept_previous_hasher(&mut self, hasher: T) {
+        self.previous_hashers.push(hasher);
+    }
+
+    pub(crate) fn hashers(&self) -> impl Iterator<Item = &T> {
+        core::iter::once(&self.hasher).chain(self.previous_hashers.iter())
+    }
+
+    /// Gets the byte checks that license keys are verified with.
+    pub fn checks(&self) -> &[ByteCheck] {
+        &self.checks
+    }
+
+    /// Adds a byte check that license keys are verified with.
+    ///
+    /// This makes it possible to rotate the checked bytes, for example
+    /// when a new version of the software is released, without creating
+    /// a new verifier.
+    pub fn add_check(&mut self, check: ByteCheck) {
+        self.checks.push(check);
+    }
+
+    /// Removes all byte checks for the specified ordinal.
+    /// Returns whether or not a byte check was removed.
+    pub fn remove_check(&mut self, ordinal: u8) -> bool {
+        let count = self.checks.len();
+        self.checks.retain(|check| check.ordinal != ordinal);
+        self.checks.len() != count
+    }
+
+    /// Treats the byte checks as a pool, and runs a random subset of
+    /// `count` byte checks every time a license key is verified.
+    ///
+    /// A cracker who finds out which bytes are checked by one verification
+    /// will have a forged license key rejected by another. The random source
+    /// decides which byte checks are selected; use a [`SeededRandom`] that
+    /// is seeded differently for every run. All byte checks in the pool must
+    /// still be part of the license key for it to be valid.
+    ///
+    /// [`SeededRandom`]: struct.SeededRandom.html
+    pub fn use_check_subset(&mut self, count: usize, random: impl RandomSource + 'static) {
+        self.check_subset = Some((count, Box::new(random)));
+    }
+
+    /// Gets the number of byte checks in the pool.
+    pub fn pool_size(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Gets the number of byte checks that are run every time a license
+    /// key is verified. See [`Verifier::use_check_subset`].
+    ///
+    /// [`Verifier::use_check_subset`]: struct.Verifier.html#method.use_check_subset
+    pub fn subset_count(&self) -> usize {
+        match &self.check_subset {
+            Some((count, _)) => (*count).min(self.checks.len()),
+            None => self.checks.len(),
+        }
+    }
+
+    pub(crate) fn select_checks(&self) -> Option<Vec<bool>> {
+        // Without a subset, every check is selected
+        let (count, random) = match &self.check_subset {
+            Some((_, random)) => (self.subset_count(), random),
+            None => return None,
+        };
+
+        // Shuffle the first part of the indices and select them
+        let mut indices: Vec<usize> = (0..self.checks.len()).collect();
+        for index in 0..count {
+            let remaining = indices.len() - index;
+            let other = index + random.next_u32() as usize % remaining;
+            indices.swap(index, other);
+        }
+        let mut selected = vec![false; self.checks.len()];
+        for index in indices[..count].iter() {
+            selected[*index] = true;
+        }
+        Some(selected)
+    }
+
+    /// Sets the initialization vector that the generator used.
+    ///
+    /// The verifier doesn't need the initialization vector to verify
+    /// license keys, but it makes it possible to validate the byte checks
+    /// using [`Verifier::validate_checks_against_iv`].
+    ///
+    /// [`Verifier::validate_checks_against_iv`]: struct.Verifier.html#method.validate_checks_against_iv
+    pub fn expect_iv(&mut self, iv: Vec<(u64, u64, u64)>) {
+        self.iv = Some(iv);
+    }
+
+    /// Sets the clock that is used to get the current time.
+    ///
+    /// Without a clock, [`Verifier::verify`] doesn't check whether or not
+    /// a license key has expired. Use a [`SystemClock`] to check against
+    /// the system time, or a [`FixedClock`] in tests.
+    ///
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    /// [`SystemClock`]: struct.SystemClock.html
+    /// [`FixedClock`]: struct.FixedClock.html
+    pub fn use_clock(&mut self, clock: impl Clock + 'static) {
+        self.clock = Some(Box::new(clock));
+    }
+
+    /// Sets the identifier of the current machine, which license keys
+    /// that are bound to a machine are verified against.
+    /// See [`Generator::generate_for_machine`].
+    ///
+    /// Since the machine identifier isn't stored in the license key, a
+    /// license key that is bound to another machine can't be told apart
+    /// from a forged one, so both are reported as [`Status::WrongMachine`].
+    /// Without a machine identifier, all license keys that are bound to a
+    /// machine are reported as [`Status::WrongMachine`] as well. License
+    /// keys that aren't bound to a machine are valid on every machine.
+    ///
+    /// [`Generator::generate_for_machine`]: struct.Generator.html#method.generate_for_machine
+    /// [`Status::WrongMachine`]: enum.Status.html#variant.WrongMachine
+    pub fn use_machine_id(&mut self, machine_id: u64) {
+        self.machine_id = Some(machine_id);
+    }
+
+    pub(crate) fn hash_seed(&self, key: &LicenseKey, hasher: &T) -> Option<u64> {
+        let hash_seed = key.get_hash_seed(hasher);
+        if !key.layout.machine {
+            return Some(hash_seed);
+        }
+        self.machine_id
+            .map(|machine_id| machine_seed(hash_seed, machine_id))
+    }
+
+    /// Sets the [`Checksum`] algorithm that license keys are validated
+    /// against, instead of the default one. The generator must use the
+    /// same algorithm using [`Generator::use_checksum`].
+    ///
+    /// [`Checksum`]: trait.Checksum.html
+    /// [`Generator::use_checksum`]: struct.Generator.html#method.use_checksum
+    pub fn use_checksum(&mut self, checksum: impl Checksum + 'static) {
+        self.checksum = Some(Box::new(checksum));
+    }
+
+    /// Validates that every byte check uses the same triplet as the
+    /// initialization vector at the same ordinal, and returns the
+    /// ordinals of the byte checks that don't.
+    ///
+    /// This makes it possible to catch misconfigured byte checks up front,
+    /// which would otherwise result in every license key being forged.
+    /// Decoy byte checks are not validated, and if no initialization
+    /// vector has been set using [`Verifier::expect_iv`], nothing is validated.
+    ///
+    /// [`Verifier::expect_iv`]: struct.Verifier.html#method.expect_iv
+    pub fn validate_checks_against_iv(&self) -> Result<(), Vec<u8>> {
+        let iv = match &self.iv {
+            Some(iv) => iv,
+            None => return Ok(()),
+        };
+
+        let mismatched: Vec<u8> = self
+            .checks
+            .iter()
+            .filter(|check| !check.decoy)
+            .filter(|check| iv.get(check.ordinal as usize) != Some(&(check.a, check.b, check.c)))
+            .map(|check| check.ordinal)
+            .collect();
+
+        if mismatched.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatched)
+        }
+    }
+
+    /// Expects the length of the license key to be folded into the checksum.
+    /// This is disabled by default.
+    ///
+    /// The generator must have the same setting enabled
+    /// using [`Generator::fold_length_into_checksum`].
+    ///
+    /// [`Generator::fold_length_into_checksum`]: struct.Generator.html#method.fold_length_into_checksum
+    pub fn fold_length_into_checksum(&mut self, enabled: bool) {
+        self.fold_length = enabled;
+    }
+
+    /// Reports blocked license keys as [`Status::Invalid`] instead of
+    /// [`Status::Blocked`] when calling [`Verifier::verify`].
+    /// This is disabled by default.
+    ///
+    /// This prevents untrusted clients from probing which seeds are blocked.
+    /// Use [`Verifier::verify_internal`] to get the real status.
+    ///
+    /// [`Status::Invalid`]: enum.Status.html#variant.Invalid
+    /// [`Status::Blocked`]: enum.Status.html#variant.Blocked
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    /// [`Verifier::verify_internal`]: struct.Verifier.html#method.verify_internal
+    pub fn conceal_blocked(&mut self, enabled: bool) {
+        self.conceal_blocked = enabled;
+    }
+
+    /// Includes the segment that a byte check expected in the mismatch
+    /// returned by [`Verifier::verify_outcome`]. This is disabled by default.
+    ///
+    /// **Only enable this while debugging.** The expected segment is exactly
+    /// what a forger needs to turn a forged license key into a valid one,
+    /// one byte check at a time.
+    ///
+    /// [`Verifier::verify_outcome`]: struct.Verifier.html#method.verify_outcome
+    pub fn reveal_expected_segments(&mut self, enabled: bool) {
+        self.reveal_expected = enabled;
+    }
+
+    /// Sets the expected payload length (the number of triplets in the
+    /// initialization vector used by the generator).
+    ///
+    /// When set, every license key is validated against the expected
+    /// length of its own [`KeyLayout`], which means that keys with and
+    /// without optional fields are both accepted as long as their length
+    /// matches their layout. Keys with an unexpected length are invalid.
+    /// Use [`Verifier::use_layouts`] to give every parsed license key the
+    /// layout of its version.
+    ///
+    /// [`KeyLayout`]: struct.KeyLayout.html
+    /// [`Verifier::use_layouts`]: struct.Verifier.html#method.use_layouts
+    pub fn expect_payload_length(&mut self, length: usize) {
+        self.payload_length = Some(length);
+    }
+
+    /// Sets the layouts of the license key versions that the verifier
+    /// accepts, which [`Verifier::verify_str`] parses license keys with.
+    ///
+    /// This makes it possible to verify license keys of different versions,
+    /// such as ones with and without an expiration time, with the same
+    /// verifier. See [`LayoutRegistry`].
+    ///
+    /// [`Verifier::verify_str`]: struct.Verifier.html#method.verify_str
+    /// [`LayoutRegistry`]: struct.LayoutRegistry.html
+    pub fn use_layouts(&mut self, layouts: LayoutRegistry) {
+        self.layouts = Some(layouts);
+    }
+
+    /// Gets the expected payload length, if one has been set.
+    /// See [`expect_payload_length`].
+    ///
+    /// [`expect_payload_length`]: struct.Verifier.html#method.expect_payload_length
+    pub fn payload_length(&self) -> Option<usize> {
+        self.payload_length
+    }
+
+    pub(crate) fn validate_ordinals(&self) -> Result<(), VerifierError> {
+        let payload_length = match self.payload_length {
+            Some(payload_length) => payload_length,
+            None => return Ok(()),
+        };
+        match self
+            .checks
+            .iter()
+            .find(|check| check.ordinal as usize >= payload_length)
+        {
+            Some(check) => Err(VerifierError::OrdinalOutOfRange {
+                ordinal: check.ordinal,
+                payload_length,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Allows at most one byte check to fail while still considering
+    /// the license key valid. This is disabled by default.
+    ///
+    /// Since the checksum must still match, a single mismatch is assumed
+    /// to be a configuration edge case (such as a legacy generator with a
+    /// known bad position) rather than a forgery.
+    ///
+    /// **Use with care.** Enabling this makes it easier to forge a license
+    /// key since a forger only has to get all but one of the checked
+    /// bytes right. Only use it as a temporary measure during migrations.
+    pub fn tolerate_single_mismatch(&mut self, enabled: bool) {
+        self.tolerate_single_mismatch = enabled;
+    }
+
+    /// Gets the reason that the specified seed was blocked with,
+    /// or `None` if it isn't blocked or was blocked without a reason.
+    pub fn block_reason(&self, seed: u64) -> Option<&str> {
+        self.block_reasons.get(&seed).map(|reason| reason.as_str())
+    }
+
+    /// Blocks all seeds between `start` and `end` (inclusive) from being used.
+    pub fn block_range(&mut self, start: u64, end: u64) {
+        self.blocked_ranges.push((start, end))
+    }
+
+    /// Blocks all seeds whose bits covered by `mask` are equal to
+    /// the same bits of `prefix`.
+    ///
+    /// This is useful when seeds have been allocated in batches that
+    /// share their high bits. For example, a prefix of `0xAB00_0000_0000_0000`
+    /// with a mask of `0xFF00_0000_0000_0000` blocks every seed
+    /// whose most significant byte is `0xAB`.
+    pub fn block_prefix(&mut self, prefix: u64, mask: u64) {
+        self.blocked_prefixes.push((prefix & mask, mask))
+    }
+
+    /// Blocks a single device for the specified seed from being used.
+    ///
+    /// Other devices for the same seed are not affected. To block
+    /// all devices for a seed, use [`Verifier::block`].
+    ///
+    /// [`Verifier::block`]: struct.Verifier.html#method.block
+    pub fn block_device(&mut self, seed: u64, device_index: u32) {
+        self.blocked_devices.push((seed, device_index))
+    }
+
+    /// Blocks the specified 128-bit seed from being used.
+    /// See [`Generator::generate_wide`].
+    ///
+    /// License keys with a 128-bit seed are only matched against the
+    /// 128-bit seeds that have been blocked, and never against the 64-bit
+    /// seeds, ranges, prefixes and devices.
+    ///
+    /// [`Generator::generate_wide`]: struct.Generator.html#method.generate_wide
+    pub fn block_wide(&mut self, seed: u128) {
+        self.blocked_wide_seeds.insert(seed);
+    }
+
+    /// Unblocks the specified 128-bit seed and returns whether or
+    /// not the seed was blocked in the first place.
+    pub fn unblock_wide(&mut self, seed: u128) -> UnblockResult {
+        if self.blocked_wide_seeds.remove(&seed) {
+            UnblockResult::Unblocked
+        } else {
+            UnblockResult::NotBlocked
+        }
+    }
+
+    /// Checks whether or not the specified seed has been
+    /// blocked, either individually, by a range or by a prefix.
+    ///
+    /// This only covers 64-bit seeds. Use [`Verifier::is_seed_blocked`]
+    /// for seeds of other lengths, and [`Verifier::is_device_blocked`]
+    /// for single devices.
+    ///
+    /// [`Verifier::is_seed_blocked`]: struct.Verifier.html#method.is_seed_blocked
+    /// [`Verifier::is_device_blocked`]: struct.Verifier.html#method.is_device_blocked
+    pub fn is_blocked(&self, seed: u64) -> bool {
+        self.blocklist.contains(seed)
+            || self
+                .blocked_ranges
+                .iter()
+                .any(|(start, end)| seed >= *start && seed <= *end)
+            || self
+                .blocked_prefixes
+                .iter()
+                .any(|(prefix, mask)| seed & mask == *prefix)
+    }
+
+    /// Checks whether or not the specified seed of any [`Seed`] type
+    /// has been blocked. 64-bit seeds are checked just like with
+    /// [`Verifier::is_blocked`].
+    ///
+    /// [`Seed`]: trait.Seed.html
+    /// [`Verifier::is_blocked`]: struct.Verifier.html#method.is_blocked
+    pub fn is_seed_blocked<S: Seed>(&self, seed: S) -> bool {
+        let bytes = seed.to_bytes();
+        match S::LENGTH {
+            SeedLength::Four => u32::from_bytes(&bytes)
+                .is_some_and(|seed| self.blocked_narrow_seeds.contains(&seed)),
+            SeedLength::Eight => u64::from_bytes(&bytes).is_some_and(|seed| self.is_blocked(seed)),
+            SeedLength::Sixteen => {
+                u128::from_bytes(&bytes).is_some_and(|seed| self.blocked_wide_seeds.contains(&seed))
+            }
+        }
+    }
+
+    /// Checks whether or not the specified device for a seed has been
+    /// blocked, either on its own or because the whole seed is blocked.
+    /// See [`Verifier::block_device`].
+    ///
+    /// [`Verifier::block_device`]: struct.Verifier.html#method.block_device
+    pub fn is_device_blocked(&self, seed: u64, device_index: u32) -> bool {
+        self.is_blocked(seed) || self.blocked_devices.contains(&(seed, device_index))
+    }
+
+    /// Perform verification on the provided license key.
+    ///
+    /// The checksum and the byte checks are compared in constant time,
+    /// which means that the time it takes to verify a license key doesn't
+    /// reveal how many bytes of it were correct.
+    pub fn verify(&self, key: &LicenseKey) -> Status {
+        self.conceal(self.verify_internal(key))
+    }
+
+    /// Perform verification on the provided license key and return
+    /// an error if it isn't valid, which works well with the `?` operator.
+    ///
+    /// This is the same as [`Verifier::verify`], but with the status
+    /// converted into a [`LicenseError`].
+    ///
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    /// [`LicenseError`]: enum.LicenseError.html
+    pub fn check(&self, key: &LicenseKey) -> Result<(), LicenseError> {
+        LicenseError::check(self.verify(key))
+    }
+
+    /// Parses the provided string by using the provided [`Serializer`]
+    /// and performs verification on the resulting license key.
+    ///
+    /// If the string can't be deserialized, the license key is invalid.
+    /// License keys are parsed with the layout of their version if
+    /// layouts have been set using [`Verifier::use_layouts`], and with
+    /// the default layout otherwise.
+    ///
+    /// [`Serializer`]: trait.Serializer.html
+    /// [`Verifier::use_layouts`]: struct.Verifier.html#method.use_layouts
+    pub fn verify_str<S: Serializer + ?Sized>(&self, input: &str, serializer: &S) -> Status {
+        let key = match &self.layouts {
+            Some(layouts) => layouts.parse(input, serializer),
+            None => LicenseKey::parse(input, serializer),
+        };
+        match key {
+            Ok(key) => self.verify(&key),
+            Err(_) => Status::Invalid,
+        }
+    }
+
+    /// Perform verification on each of the provided license keys.
+    ///
+    /// The statuses are returned in the same order as the license keys.
+    pub fn verify_many<I: IntoIterator<Item = LicenseKey>>(&self, keys: I) -> Vec<Status> {
+        keys.into_iter().map(|key| self.verify(&key)).collect()
+    }
+
+    /// Perform verification on each of the provided license keys in parallel.
+    ///
+    /// The statuses are returned in the same order as the license keys,
+    /// just like for [`verify_many`].
+    ///
+    /// [`verify_many`]: struct.Verifier.html#method.verify_many
+    #[cfg(feature = "rayon")]
+    pub fn verify_many_parallel(&self, keys: &[LicenseKey]) -> Vec<Status>
+    where
+        T: Sync,
+        B: Sync,
+    {
+        use rayon::prelude::*;
+        keys.par_iter().map(|key| self.verify(key)).collect()
+    }
+
+    /// Perform verification on the provided license key at the
+    /// specified time (as a Unix timestamp).
+    ///
+    /// If the license key contains an expiration time that has
+    /// passed, the license key is considered expired.
+    /// See [`Generator::generate_with_expiry`].
+    ///
+    /// [`Generator::generate_with_expiry`]: struct.Generator.html#method.generate_with_expiry
+    pub fn verify_at(&self, key: &LicenseKey, now: u64) -> Status {
+        match self.decode(key) {
+            Ok(decoded) => self.decide(&decoded, now),
+            Err(_) => Status::Invalid,
+        }
+    }
+
+    /// Perform verification on the provided license key for the
+    /// region that the caller is currently in.
+    ///
+    /// License keys that are locked to another region are reported as
+    /// [`Status::WrongRegion`], while license keys without a region code
+    /// are valid in every region.
+    ///
+    /// [`Status::WrongRegion`]: enum.Status.html#variant.WrongRegion
+    pub fn verify_for_region(&self, key: &LicenseKey, current_region: u16) -> Status {
+        let status = self.verify(key);
+        if !status.is_valid() {
+            return status;
+        }
+
+        match key.region() {
+            Some(region) if region != current_region => Status::WrongRegion,
+            _ => status,
+        }
+    }
+
+    /// Perform verification on the provided license keys and
+    /// return only the ones that aren't valid, together with their status.
+    ///
+    /// The license keys are verified lazily as the iterator is consumed.
+    pub fn verify_failures<'a>(
+        &'a self,
+        keys: impl Iterator<Item = &'a LicenseKey> + 'a,
+    ) -> impl Iterator<Item = (&'a LicenseKey, Status)> + 'a {
+        keys.filter_map(move |key| match self.verify(key) {
+            status if status.is_valid() => None,
+            status => Some((key, status)),
+        })
+    }
+
+    /// Perform verification on the provided license key and
+    /// return the status together with the version of the key.
+    ///
+    /// The version is returned regardless of the status, as long as
+    /// the license key contains a version. See [`LicenseKey::version`].
+    ///
+    /// [`LicenseKey::version`]: struct.LicenseKey.html#method.version
+    pub fn verify_with_version(&self, key: &LicenseKey) -> (Status, Option<u8>) {
+        (self.verify(key), key.version())
+    }
+
+    /// Perform verification on the provided license key without
+    /// concealing blocked license keys.
+    ///
+    /// This should only be used for trusted purposes such as logging
+    /// or administration, and never to respond to untrusted clients.
+    pub fn verify_internal(&self, key: &LicenseKey) -> Status {
+        self.verify_with_reason(key, &mut None).0
+    }
+
+    /// Perform verification on the provided license key and return
+    /// the status together with the reason and any extracted claims.
+    ///
+    /// If the license key is forged, the first byte check that didn't
+    /// match is included as well, which is useful when debugging a
+    /// misconfigured initialization vector. The segment that the byte check
+    /// expected is only included if [`Verifier::reveal_expected_segments`]
+    /// has been enabled. If the license key is blocked, the blocklist entry
+    /// that matched is included, together with the reason that the seed
+    /// was blocked with.
+    ///
+    /// Just like [`Verifier::verify`], blocked keys are concealed if
+    /// [`Verifier::conceal_blocked`] has been enabled. Note that the
+    /// claims are not authenticated by this method.
+    ///
+    /// This should only be used for trusted purposes such as logging
+    /// or administration, and never to respond to untrusted clients,
+    /// since the outcome tells a forger which byte check to work on.
+    ///
+    /// [`Verifier::reveal_expected_segments`]: struct.Verifier.html#method.reveal_expected_segments
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    /// [`Verifier::conceal_blocked`]: struct.Verifier.html#method.conceal_blocked
+    pub fn verify_outcome(&self, key: &LicenseKey) -> VerificationOutcome {
+        let mut selected = None;
+        let (status, reason) = match self.verify_with_reason(key, &mut selected) {
+            (Status::Blocked, _) if self.conceal_blocked => (Status::Invalid, None),
+            outcome => outcome,
+        };
+        let claims = match status {
+            Status::Valid | Status::Trial { .. } => key.claims(),
+            _ => None,
+        };
+        let mismatch = match status {
+            Status::Forged => self.first_mismatch(key, selected.as_deref()),
+            _ => None,
+        };
+        let blocked = match status {
+            Status::Blocked => self.matching_block_entry(key).map(|entry| BlockMatch {
+                entry,
+                reason: match entry {
+                    BlockEntry::Seed(seed) => self.block_reasons.get(&seed).cloned(),
+                    _ => None,
+                },
+            }),
+            _ => None,
+        };
+        VerificationOutcome {
+            status,
+            reason,
+            claims,
+            mismatch,
+            blocked,
+        }
+    }
+
+    fn first_mismatch(&self, key: &LicenseKey, selected: Option<&[bool]>) -> Option<Mismatch> {
+        let segment_length = key.layout.segment_length;
+
+        // Only the byte checks that the status was decided with are scanned
+        let checks = self
+            .checks
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| selected.is_none_or(|selected| selected[*index]))
+            .map(|(_, check)| check);
+
+        // Report the mismatch for the hasher with the fewest mismatches,
+        // since that's the hasher that the status was decided with.
+        let mut closest: Option<(usize, Mismatch)> = None;
+        for hasher in self.hashers() {
+            let hash_seed = self.hash_seed(key, hasher)?;
+            let mut mismatches = checks.clone().filter_map(|check| {
+                let expected = check.expected(hasher, hash_seed, segment_length);
+                match key.get_segment(check.ordinal as usize).map(segment_value) {
+                    Some(actual) if actual != expected => Some(Mismatch {
+                        ordinal: check.ordinal,
+                        expected: self.reveal_expected.then_some(expected),
+                        actual,
+                    }),
+                    _ => None,
+                }
+            });
+            // A hasher without mismatches means that the key isn't forged
+            let first = mismatches.next()?;
+            let count = 1 + mismatches.count();
+            if closest.as_ref().is_none_or(|(fewest, _)| count < *fewest) {
+                closest = Some((count, first));
+            }
+        }
+        closest.map(|(_, mismatch)| mismatch)
+    }
+
+    /// Decodes the provided license key without deciding whether or not
+    /// it's allowed, which makes it possible to log what the license key
+    /// says even if it's blocked or expired.
+    ///
+    /// Only the length and the checksums are validated. If they don't
+    /// match, the reason is returned as an error. Pass the decoded license
+    /// key to [`Verifier::decide`] to get the final status.
+    ///
+    /// [`Verifier::decide`]: struct.Verifier.html#method.decide
+    pub fn decode<'a>(&self, key: &'a LicenseKey) -> Result<DecodedKey<'a>, Reason> {
+        // Validate the length
+        if key.bytes.len() < key.layout.key_length(0) {
+            return Err(Reason::Length);
+        }
+        if let Some(payload_length) = self.payload_length {
+            if key.bytes.len() != key.layout.key_length(payload_length) {
+                return Err(Reason::Length);
+            }
+        }
+
+        // Validate the checksum
+        let checksum = key.calculate_checksum(self.checksum.as_deref(), self.fold_length);
+        if !constant_time_eq(&checksum, key.get_checksum()) {
+            return Err(Reason::Checksum);
+        }
+
+        // Validate the seed checksum
+        if key.layout.seed_checksum && !key.seed_checksum_ok() {
+            return Err(Reason::Checksum);
+        }
+
+        Ok(DecodedKey {
+            seed: key.wide_seed().unwrap_or_default(),
+            device_index: key.device_index(),
+            expires_at: key.expires_at(),
+            claims: key.claims(),
+            key,
+        })
+    }
+
+    /// Decides the status of a license key decoded by [`Verifier::decode`].
+    ///
+    /// Blocked seeds and byte checks are verified just like in
+    /// [`Verifier::verify`]. If the license key contains an expiration
+    /// time or claims that expired before `now`, the license key is
+    /// considered expired.
+    /// The time unit is decided by the caller, but must be the same that
+    /// was used for [`Claims::expires_at`]. Note that the claims are not
+    /// authenticated by this method.
+    ///
+    /// [`Verifier::decode`]: struct.Verifier.html#method.decode
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    /// [`Claims::expires_at`]: struct.Claims.html#structfield.expires_at
+    pub fn decide(&self, decoded: &DecodedKey, now: u64) -> Status {
+        self.conceal(self.decide_with_reason(decoded, Some(now), &mut None).0)
+    }
+
+    fn verify_with_reason(
+        &self,
+        key: &LicenseKey,
+        selected: &mut Option<Vec<bool>>,
+    ) -> (Status, Option<Reason>) {
+        match self.decode(key) {
+            Ok(decoded) => {
+                let now = self.clock.as_ref().map(|clock| clock.now_unix());
+                self.decide_with_reason(&decoded, now, selected)
+            }
+            Err(reason) => (Status::Invalid, Some(reason)),
+        }
+    }
+
+    /// Decides the status of the decoded license key, and keeps the byte
+    /// checks that were selected for it in `selected`, if any.
+    fn decide_with_reason(
+        &self,
+        decoded: &DecodedKey,
+        now: Option<u64>,
+        selected: &mut Option<Vec<bool>>,
+    ) -> (Status, Option<Reason>) {
+        let key = decoded.key;
+
+        // Blocked key?
+        if let Some(reason) = self.blocked(key) {
+            return (Status::Blocked, Some(reason));
+        }
+
+        let segment_length = key.layout.segment_length;
+
+        // Every hasher is tried, so that the time it takes doesn't
+        // reveal which hasher the license key was generated with.
+        *selected = self.select_checks();
+        let mut mismatches = usize::MAX;
+        for hasher in self.hashers() {
+            // Seeds that aren't 64 bits long are folded by every hasher
+            let hash_seed = match self.hash_seed(key, hasher) {
+                Some(hash_seed) => hash_seed,
+                None => return (Status::WrongMachine, Some(Reason::WrongMachine)),
+            };
+            let mut hasher_mismatches = 0;
+            for (index, check) in self.checks.iter().enumerate() {
+                match key.get_segment(check.ordinal as usize) {
+                    Some(_) if selected.as_ref().is_some_and(|selected| !selected[index]) => {}
+                    Some(segment) => {
+                        let expected = check.expected(hasher, hash_seed, segment_length);
+                        hasher_mismatches +=
+                            constant_time_mismatch(segment_value(segment), expected);
+                    }
+                    None => {
+                        // If we couldn't get the byte from the license
+                        // the license is invalid.
+                        return (Status::Invalid, Some(Reason::MissingByte));
+                    }
+                }
+            }
+            mismatches = mismatches.min(hasher_mismatches);
+        }
+
+        if mismatches > self.allowed_mismatches() {
+            if key.layout.machine {
+                // A key for another machine looks just like a forged one
+                return (Status::WrongMachine, Some(Reason::WrongMachine));
+            }
+
+            // Values did not match, but the checksum
+            // was correct, so this is a forged license key
+            return (Status::Forged, Some(Reason::ByteCheck));
+        }
+
+        self.expiry_status(key, decoded.expires_at, decoded.claims.as_ref(), now)
+    }
+
+    /// Decides the status of a license key that passed the byte checks,
+    /// by checking the expiration time, then the expiration time of the
+    /// claims and lastly whether or not the license key is a trial.
+    pub(crate) fn expiry_status(
+        &self,
+        key: &LicenseKey,
+        expires_at: Option<u32>,
+        claims: Option<&Claims>,
+        now: Option<u64>,
+    ) -> (Status, Option<Reason>) {
+        // Expired key?
+        if let (Some(now), Some(expires_at)) = (now, expires_at) {
+            if (expires_at as u64) < now {
+                return (Status::Expired, Some(Reason::Expired));
+            }
+        }
+        if let (Some(now), Some(claims)) = (now, claims) {
+            // Claims that expire at zero never expire
+            if claims.expires_at != 0 && (claims.expires_at as u64) < now {
+                return (Status::Expired, Some(Reason::Expired));
+            }
+        }
+
+        if !key.is_trial() {
+            return (Status::Valid, None);
+        }
+
+        // Without the current time, there's no telling whether or not
+        // the trial has expired, so it's treated as expired.
+        match (now, expires_at) {
+            (Some(now), Some(expires_at)) => {
+                let seconds = (expires_at as u64).saturating_sub(now);
+                let days_remaining = seconds.div_ceil(SECONDS_PER_DAY as u64) as u32;
+                (Status::Trial { days_remaining }, None)
+            }
+            _ => (Status::Expired, Some(Reason::Expired)),
+        }
+    }
+
+    pub(crate) fn blocked(&self, key: &LicenseKey) -> Option<Reason> {
+        self.matching_block_entry(key).map(|entry| match entry {
+            BlockEntry::Seed(_) | BlockEntry::NarrowSeed(_) | BlockEntry::WideSeed(_) => {
+                Reason::BlockedSeed
+            }
+            BlockEntry::Range(..) => Reason::BlockedRange,
+            BlockEntry::Prefix(..) => Reason::BlockedPrefix,
+            BlockEntry::Device(..) => Reason::BlockedDevice,
+        })
+    }
+
+    fn matching_block_entry(&self, key: &LicenseKey) -> Option<BlockEntry> {
+        if let Some(seed) = key.seed_as::<u32>() {
+            if self.blocked_narrow_seeds.contains(&seed) {
+                return Some(BlockEntry::NarrowSeed(seed));
+            }
+            return None;
+        }
+        if let Some(seed) = key.seed_as::<u128>() {
+            if self.blocked_wide_seeds.contains(&seed) {
+                return Some(BlockEntry::WideSeed(seed));
+            }
+            return None;
+        }
+
+        // License keys that are too short to contain a seed never
+        // pass verification, so any seed will do for them.
+        let seed = key.seed().unwrap_or_default();
+        if self.blocklist.contains(seed) {
+            return Some(BlockEntry::Seed(seed));
+        }
+
+        for (start, end) in self.blocked_ranges.iter() {
+            if seed >= *start && seed <= *end {
+                return Some(BlockEntry::Range(*start, *end));
+            }
+        }
+
+        for (prefix, mask) in self.blocked_prefixes.iter() {
+            if seed & mask == *prefix {
+                return Some(BlockEntry::Prefix(*prefix, *mask));
+            }
+        }
+
+        if let Some(device_index) = key.device_index() {
+            if self.blocked_devices.contains(&(seed, device_index)) {
+                return Some(BlockEntry::Device(seed, device_index));
+            }
+        }
+
+        None
+    }
+
+    pub(crate) fn allowed_mismatches(&self) -> usize {
+        if self.tolerate_single_mismatch {
+            1
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn conceal(&self, status: Status) -> Status {
+        match status {
+            Status::Blocked if self.conceal_blocked => Status::Invalid,
+            status => status,
+        }
+    }
+}
+
+/// Represents something that can verify a license key.
+pub trait KeyVerifier {
+    /// Perform verification on the provided license key.
+    fn verify(&self, key: &LicenseKey) -> Status;
+}
+
+impl<T: KeyHasher, B: BlockList> KeyVerifier for Verifier<T, B> {
+    fn verify(&self, key: &LicenseKey) -> Status {
+        Verifier::verify(self, key)
+    }
+}
+
+/// A license key verifier that consults multiple verifiers.
+///
+/// This is useful during migrations where license keys generated
+/// with an old configuration must keep working alongside new ones.
+#[derive(Default)]
+pub struct MultiVerifier {
+    verifiers: Vec<Box<dyn KeyVerifier>>,
+}
+
+impl MultiVerifier {
+    /// Creates a new multi verifier without any verifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a verifier that should be consulted.
+    pub fn add(&mut self, verifier: impl KeyVerifier + 'static) {
+        self.verifiers.push(Box::new(verifier));
+    }
+
+    /// Perform verification on the provided license key.
+    ///
+    /// The license key is valid if any of the verifiers consider it valid.
+    /// Otherwise, the first status that isn't [`Status::Invalid`] is returned
+    /// since it tells more about why the license key isn't valid.
+    ///
+    /// [`Status::Invalid`]: enum.Status.html#variant.Invalid
+    pub fn verify(&self, key: &LicenseKey) -> Status {
+        let mut result = Status::Invalid;
+        for verifier in self.verifiers.iter() {
+            match verifier.verify(key) {
+                status if status.is_valid() => return status,
+                Status::Invalid => {}
+                status => {
+                    if result == Status::Invalid {
+                        result = status;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// A license key verifier that routes license keys to
+/// different verifiers depending on their version.
+///
+/// This makes it possible to rotate the initialization vector or the
+/// hasher, since license keys generated with different schemes can
+/// be told apart. See [`Generator::include_version`]. License keys must
+/// be parsed with a [`KeyLayout`] where `version` is set, or with a
+/// [`LayoutRegistry`] if the versions have different layouts.
+///
+/// [`Generator::include_version`]: struct.Generator.html#method.include_version
+/// [`KeyLayout`]: struct.KeyLayout.html
+/// [`LayoutRegistry`]: struct.LayoutRegistry.html
+#[derive(Default)]
+pub struct VersionedVerifier {
+    verifiers: Map<u8, Box<dyn KeyVerifier>>,
+}
+
+impl VersionedVerifier {
+    /// Creates a new versioned verifier without any verifiers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the verifier for license keys with the specified version,
+    /// replacing any verifier previously added for that version.
+    pub fn add(&mut self, version: u8, verifier: impl KeyVerifier + 'static) {
+        self.verifiers.insert(version, Box::new(verifier));
+    }
+
+    /// Perform verification on the provided license key.
+    ///
+    /// If the license key doesn't contain a version, or if no verifier
+    /// has been added for its version, [`Status::UnsupportedVersion`]
+    /// is returned.
+    ///
+    /// [`Status::UnsupportedVersion`]: enum.Status.html#variant.UnsupportedVersion
+    pub fn verify(&self, key: &LicenseKey) -> Status {
+        let verifier = key
+            .version()
+            .and_then(|version| self.verifiers.get(&version));
+        match verifier {
+            Some(verifier) => verifier.verify(key),
+            None => Status::UnsupportedVersion,
+        }
+    }
+}
+
+impl KeyVerifier for VersionedVerifier {
+    fn verify(&self, key: &LicenseKey) -> Status {
+        VersionedVerifier::verify(self, key)
+    }
+}
+
+fn device_seed(seed: u64, device_index: u32) -> u64 {
+    // Offset the device index by one so that the seed
+    // for the first device differs from the account seed.
+    seed ^ (device_index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+fn expiry_seed(seed: u64, expires_at: u32) -> u64 {
+    seed ^ (expires_at as u64 + 1).wrapping_mul(0x1656_67B1_9E37_79F9)
+}
+
+fn issue_date_seed(seed: u64, issue_date: u16) -> u64 {
+    seed ^ (issue_date as u64 + 1).wrapping_mul(0x9FB2_1C65_1E98_DF25)
+}
+
+fn flags_seed(seed: u64, flags: u8) -> u64 {
+    // Offset the flags by one so that the seed for
+    // a key without any flags differs from the account seed.
+    seed ^ (flags as u64 + 1).wrapping_mul(0xD6E8_FEB8_6659_FD93)
+}
+
+fn features_seed(seed: u64, features: u32) -> u64 {
+    // Offset the features by one so that the seed for
+    // a key without any features differs from the account seed.
+    seed ^ (features as u64 + 1).wrapping_mul(0x27D4_EB2F_1656_67C5)
+}
+
+fn machine_seed(seed: u64, machine_id: u64) -> u64 {
+    // Offset the machine identifier by one so that the seed
+    // for the first machine differs from the account seed.
+    seed ^ (machine_id.wrapping_add(1)).wrapping_mul(0x85EB_CA77_C2B2_AE63)
+}
+
+fn region_seed(seed: u64, region: u16) -> u64 {
+    // Offset the region by one so that the seed
+    // for the first region differs from the account seed.
+    seed ^ (region as u64 + 1).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+}
+
+fn sequence_round_key(secret: &[u8], round: u8) -> u64 {
+    // FNV-1a over the round and the secret
+    let mut hash = 0xCBF2_9CE4_8422_2325_u64;
+    for byte in core::iter::once(&round).chain(secret.iter()) {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    hash
+}
+
+fn mix_round(value: u32, key: u64) -> u32 {
+    // Mix the values using the SplitMix64 finalizer
+    let mut value = value as u64 ^ key;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (value ^ (value >> 31)) as u32
+}
+
+fn mix_wide_seed(high: u64, low: u64, length: u64, index: u64) -> u64 {
+    // Mix both halves using the SplitMix64 finalizer, so that seeds
+    // that only differ in how their halves relate don't collide.
+    let mut value = high ^ ((length << 8) | index).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^= (value >> 31) ^ low;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+fn decoy_segment(seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+    for (index, byte) in segment.iter_mut().enumerate() {
+        *byte = decoy_byte(segment_seed(seed, index), a, b, c);
+    }
+}
+
+fn segment_seed(seed: u64, index: usize) -> u64 {
+    // The first byte of a segment uses the seed as is, which
+    // keeps one-byte segments the same as they've always been.
+    seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+fn segment_value(segment: &[u8]) -> u32 {
+    segment
+        .iter()
+        .fold(0, |value, byte| (value << 8) | *byte as u32)
+}
+
+fn decoy_byte(seed: u64, a: u64, b: u64, c: u64) -> u8 {
+    // Mix the values using the SplitMix64 finalizer
+    let mut value = seed ^ a.rotate_left(16) ^ b.rotate_left(32) ^ c.rotate_left(48);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (value ^ (value >> 31)) as u8
+}
+
+fn calculate_seed_checksum(seed: &[u8]) -> u8 {
+    // CRC-8 with the polynomial 0x07
+    let mut crc = 0_u8;
+    for byte in seed.iter() {
+        crc ^= *byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    // Accumulate the differences instead of
+    // returning at the first byte that differs.
+    let mut difference = 0_u8;
+    for (a, b) in a.iter().zip(b.iter()) {
+        difference |= a ^ b;
+    }
+    difference == 0
+}
+
+fn constant_time_mismatch(a: u32, b: u32) -> usize {
+    // Maps any difference to 1, and no difference to 0, without branching
+    let difference = a ^ b;
+    ((difference | difference.wrapping_neg()) >> 31) as usize
+}
+
+fn compute_checksum(
+    checksum: Option<&dyn Checksum>,
+    key: &[u8],
+    fold_length: bool,
+    long_checksum: bool,
+) -> Vec<u8> {
+    match checksum {
+        Some(checksum) if fold_length => {
+            // Folding the length is the same as appending it
+            let mut data = key.to_vec();
+            data.extend_from_slice(&(key.len() as u32).to_be_bytes());
+            checksum.compute(&data)
+        }
+        Some(checksum) => checksum.compute(key),
+        None => calculate_checksum(key, fold_length, long_checksum),
+    }
+}
+
+fn calculate_checksum(key: &[u8], fold_length: bool, long_checksum: bool) -> Vec<u8> {
+    let mut checksum = ChecksumState::new();
+    for byte in key.iter() {
+        checksum.update(*byte);
+    }
+    if fold_length {
+        checksum.fold_length(key.len());
+    }
+    if long_checksum {
+        checksum.finish_long().to_vec()
+    } else {
+        checksum.finish().to_vec()
+    }
+}
+
+/// The state of a checksum calculation, which makes
+/// it possible to calculate the checksum incrementally.
+#[derive(Clone)]
+pub(crate) struct ChecksumState {
+    left: u16,
+    right: u16,
+    long_left: u32,
+    long_right: u32,
+}
+
+impl ChecksumState {
+    pub fn new() -> Self {
+        Self::with_seeds(0x56, 0xAF)
+    }
+
+    pub fn with_seeds(left: u8, right: u8) -> Self {
+        Self {
+            left: left as u16,
+            right: right as u16,
+            long_left: 0x5A56,
+            long_right: 0xA5AF,
+        }
+    }
+
+    pub fn update(&mut self, byte: u8) {
+        // Both sums stay within 0..=255, so a single subtraction
+        // is enough to reduce them modulo 255 after each byte.
+        self.right += byte as u16;
+        if self.right > 0xFF {
+            self.right -= 0xFF;
+        }
+        self.left += self.right;
+        if self.left > 0xFF {
+            self.left -= 0xFF;
+        }
+
+        // The long checksum works the same way, but modulo 0xFFFF
+        self.long_right = (self.long_right + byte as u32) % 0xFFFF;
+        self.long_left = (self.long_left + self.long_right) % 0xFFFF;
+    }
+
+    pub fn fold_length(&mut self, length: usize) {
+        for byte in (length as u32).to_be_bytes().iter() {
+            self.update(*byte);
+        }
+    }
+
+    pub fn finish(&self) -> [u8; 2] {
+        ((self.left << 8) + self.right).to_be_bytes()
+    }
+
+    pub fn finish_long(&self) -> [u8; 4] {
+        ((self.long_left << 16) + self.long_right).to_be_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyHasher;
+    use crate::Generator;
+
+    #[derive(Default)]
+    pub struct TestHasher {}
+    impl KeyHasher for TestHasher {
+        fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+            ((seed ^ a ^ b ^ c) & 0xFF) as u8
+        }
+    }
+
+    pub fn create_generator() -> Generator<TestHasher> {
+        Generator::new(
+            TestHasher::default(),
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)],
+        )
+    }
+
+    pub fn generate_key(seed: u64) -> LicenseKey {
+        create_generator().generate(seed)
+    }
+
+    pub fn create_verifier() -> Verifier<TestHasher> {
+        Verifier::new(
+            TestHasher::default(),
+            vec![
+                ByteCheck::new(0, (114, 83, 170)),
+                ByteCheck::new(2, (69, 14, 202)),
+            ],
+        )
+    }
+
+    #[test]
+    pub fn valid_key_should_be_valid() {
+        // Given
+        let key = generate_key(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn valid_but_blocked_key_should_return_error() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Blocked, result);
+    }
+
+    #[test]
+    pub fn pruned_seeds_should_no_longer_be_blocked() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.block(67890);
+
+        // When
+        verifier.prune_blocklist(|seed| seed != 12345);
+
+        // Then
+        assert_eq!(Status::Valid, verifier.verify(&generate_key(12345)));
+        assert_eq!(Status::Blocked, verifier.verify(&generate_key(67890)));
+    }
+
+    #[test]
+    pub fn tolerant_verifier_should_accept_key_without_mismatches() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.tolerate_single_mismatch(true);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn tolerant_verifier_should_accept_key_with_single_mismatch() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = Verifier::new(
+            TestHasher::default(),
+            vec![
+                ByteCheck::new(0, (114, 83, 170)),
+                ByteCheck::new(2, (1, 2, 3)),
+            ],
+        );
+        verifier.tolerate_single_mismatch(true);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn tolerant_verifier_should_reject_key_with_two_mismatches() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = Verifier::new(
+            TestHasher::default(),
+            vec![ByteCheck::new(0, (4, 5, 6)), ByteCheck::new(2, (1, 2, 3))],
+        );
+        verifier.tolerate_single_mismatch(true);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn device_keys_should_share_seed_but_differ() {
+        // Given
+        let generator = create_generator();
+
+        // When
+        let first = generator.generate_device_key(12345, 0);
+        let second = generator.generate_device_key(12345, 1);
+
+        // Then
+        assert_eq!(Some(12345), first.seed());
+        assert_eq!(Some(12345), second.seed());
+        assert_eq!(Some(0), first.device_index());
+        assert_eq!(Some(1), second.device_index());
+        assert_ne!(first.get_bytes(), second.get_bytes());
+    }
+
+    #[test]
+    pub fn parsed_device_key_should_be_valid() {
+        // Given
+        let key = create_generator().generate_device_key(12345, 3);
+        let layout = KeyLayout {
+            device_index: true,
+            ..KeyLayout::default()
+        };
+        let verifier = create_verifier();
+
+        // When
+        let key =
+            LicenseKey::parse_with_layout(&key.serialize(&HexFormat), &HexFormat, layout).unwrap();
+
+        // Then
+        assert_eq!(Some(3), key.device_index());
+        assert_eq!(Status::Valid, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn blocking_device_should_not_block_other_devices() {
+        // Given
+        let generator = create_generator();
+        let mut verifier = create_verifier();
+        verifier.block_device(12345, 1);
+
+        // When
+        let first = verifier.verify(&generator.generate_device_key(12345, 0));
+        let second = verifier.verify(&generator.generate_device_key(12345, 1));
+
+        // Then
+        assert_eq!(Status::Valid, first);
+        assert_eq!(Status::Blocked, second);
+    }
+
+    #[test]
+    pub fn blocking_seed_should_block_all_devices() {
+        // Given
+        let generator = create_generator();
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+
+        // When
+        let first = verifier.verify(&generator.generate_device_key(12345, 0));
+        let second = verifier.verify(&generator.generate_device_key(12345, 1));
+
+        // Then
+        assert_eq!(Status::Blocked, first);
+        assert_eq!(Status::Blocked, second);
+    }
+
+    #[test]
+    pub fn keys_with_different_layouts_should_be_valid_with_expected_length() {
+        // Given
+        let generator = create_generator();
+        let mut verifier = create_verifier();
+        verifier.expect_payload_length(4);
+
+        // When
+        let plain = verifier.verify(&generator.generate(12345));
+        let device = verifier.verify(&generator.generate_device_key(12345, 1));
+
+        // Then
+        assert_eq!(Status::Valid, plain);
+        assert_eq!(Status::Valid, device);
+    }
+
+    #[test]
+    pub fn key_with_unexpected_length_should_be_invalid() {
+        // Given
+        let generator = create_generator();
+        let mut verifier = create_verifier();
+        verifier.expect_payload_length(5);
+
+        // When
+        let plain = verifier.verify(&generator.generate(12345));
+        let device = verifier.verify(&generator.generate_device_key(12345, 1));
+
+        // Then
+        assert_eq!(Status::Invalid, plain);
+        assert_eq!(Status::Invalid, device);
+    }
+
+    #[test]
+    pub fn concealed_blocked_key_should_be_invalid() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.conceal_blocked(true);
+
+        // When
+        let external = verifier.verify(&key);
+        let internal = verifier.verify_internal(&key);
+
+        // Then
+        assert_eq!(Status::Invalid, external);
+        assert_eq!(Status::Blocked, internal);
+    }
+
+    #[test]
+    pub fn distinct_hashers_should_produce_different_payloads() {
+        // Given
+        struct OtherHasher {}
+        impl KeyHasher for OtherHasher {
+            fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+                ((seed.rotate_left(8) ^ a.wrapping_mul(b) ^ c) & 0xFF) as u8
+            }
+        }
+        let old = create_generator();
+        let new = Generator::new(
+            OtherHasher {},
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)],
+        );
+
+        // When
+        let identical = count_identical_payloads(&old, &new, 0..1000);
+
+        // Then
+        assert_eq!(0, identical);
+        assert_eq!(1000, count_identical_payloads(&old, &old, 0..1000));
+    }
+
+    pub struct RotatedHasher {}
+    impl KeyHasher for RotatedHasher {
+        fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+            ((seed.rotate_left(8) ^ a.wrapping_mul(b) ^ c) & 0xFF) as u8
+        }
+    }
+
+    pub fn create_rotating_verifier() -> Verifier<Box<dyn KeyHasher>> {
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(2, (69, 14, 202)),
+        ];
+        let mut verifier: Verifier<Box<dyn KeyHasher>> =
+            Verifier::new(Box::new(RotatedHasher {}), checks);
+        verifier.accept_previous_hasher(Box::new(TestHasher::default()));
+        verifier
+    }
+
+    #[test]
+    pub fn verifier_with_previous_hasher_should_accept_old_and_new_keys() {
+        // Given
+        let new = Generator::new(
+            RotatedHasher {},
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)],
+        );
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let verifier = create_rotating_verifier();
+
+        // When
+        let old_key = verifier.verify(&generate_key(12345));
+        let new_key = verifier.verify(&new.generate(12345));
+        let bogus_key = verifier.verify(&forger.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, old_key);
+        assert_eq!(Status::Valid, new_key);
+        assert_eq!(Status::Forged, bogus_key);
+    }
+
+    #[test]
+    pub fn verifier_without_previous_hasher_should_reject_old_keys() {
+        // Given
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(2, (69, 14, 202)),
+        ];
+        let verifier = Verifier::new(RotatedHasher {}, checks);
+
+        // When
+        let result = verifier.verify(&generate_key(12345));
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn outcome_should_contain_reason() {
+        // Given
+        let verifier = create_verifier();
+        let mut bytes = generate_key(12345).get_bytes();
+        bytes[8] ^= 0xFF;
+
+        // When
+        let result = verifier.verify_outcome(&LicenseKey::new(bytes, KeyLayout::default()));
+
+        // Then
+        assert_eq!(Status::Invalid, result.status);
+        assert_eq!(Some(Reason::Checksum), result.reason);
+    }
+
+    #[test]
+    pub fn forged_outcome_should_contain_first_mismatch() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.reveal_expected_segments(true);
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let key = forger.generate(12345);
+        let expected = ByteCheck::new(0, (114, 83, 170)).expected(
+            &TestHasher::default(),
+            key.get_hash_seed(&TestHasher::default()),
+            SegmentLength::One,
+        );
+
+        // When
+        let forged = verifier.verify_outcome(&key);
+        let valid = verifier.verify_outcome(&generate_key(12345));
+
+        // Then
+        assert_eq!(
+            Some(Mismatch {
+                ordinal: 0,
+                expected: Some(expected),
+                actual: key.get_bytes()[8] as u32,
+            }),
+            forged.mismatch
+        );
+        assert_eq!(None, valid.mismatch);
+    }
+
+    #[test]
+    pub fn forged_outcome_should_contain_mismatch_of_selected_check() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.use_check_subset(1, SeededRandom::new(12345));
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let key = forger.generate(12345);
+
+        // When
+        let mut ordinals: Vec<u8> = (0..16)
+            .filter_map(|_| verifier.verify_outcome(&key).mismatch)
+            .map(|mismatch| mismatch.ordinal)
+            .collect();
+        ordinals.sort_unstable();
+        ordinals.dedup();
+
+        // Then
+        assert_eq!(vec![0, 2], ordinals);
+    }
+
+    #[test]
+    pub fn forged_outcome_should_not_reveal_expected_segment_by_default() {
+        // Given
+        let verifier = create_verifier();
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let key = forger.generate(12345);
+
+        // When
+        let forged = verifier.verify_outcome(&key);
+
+        // Then
+        assert_eq!(
+            Some(Mismatch {
+                ordinal: 0,
+                expected: None,
+                actual: key.get_bytes()[8] as u32,
+            }),
+            forged.mismatch
+        );
+    }
+
+    #[test]
+    pub fn forged_outcome_should_contain_mismatch_of_closest_hasher() {
+        // Given
+        let mut verifier = create_rotating_verifier();
+        verifier.reveal_expected_segments(true);
+        let forger = Generator::new(
+            TestHasher::default(),
+            vec![(114, 83, 170), (4, 5, 6), (7, 8, 9)],
+        );
+        let key = forger.generate(12345);
+        let expected = ByteCheck::new(2, (69, 14, 202)).expected(
+            &TestHasher::default(),
+            key.get_hash_seed(&TestHasher::default()),
+            SegmentLength::One,
+        );
+
+        // When
+        let outcome = verifier.verify_outcome(&key);
+
+        // Then
+        assert_eq!(Status::Forged, outcome.status);
+        assert_eq!(
+            Some(Mismatch {
+                ordinal: 2,
+                expected: Some(expected),
+                actual: key.get_bytes()[10] as u32,
+            }),
+            outcome.mismatch
+        );
+    }
+
+    #[test]
+    pub fn outcome_should_contain_matching_block_entry() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_with_reason(12345, "chargeback".to_string());
+        verifier.block_range(60000, 70000);
+
+        // When
+        let exact = verifier.verify_outcome(&generate_key(12345));
+        let range = verifier.verify_outcome(&generate_key(67890));
+        let valid = verifier.verify_outcome(&generate_key(54321));
+
+        // Then
+        assert_eq!(Status::Blocked, exact.status);
+        assert_eq!(
+            Some(BlockMatch {
+                entry: BlockEntry::Seed(12345),
+                reason: Some("chargeback".to_string()),
+            }),
+            exact.blocked
+        );
+        assert_eq!(Some(Reason::BlockedRange), range.reason);
+        assert_eq!(
+            Some(BlockMatch {
+                entry: BlockEntry::Range(60000, 70000),
+                reason: None,
+            }),
+            range.blocked
+        );
+        assert_eq!(None, valid.blocked);
+    }
+
+    #[test]
+    pub fn concealed_outcome_should_not_contain_block_entry() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.conceal_blocked(true);
+
+        // When
+        let result = verifier.verify_outcome(&generate_key(12345));
+
+        // Then
+        assert_eq!(Status::Invalid, result.status);
+        assert_eq!(None, result.blocked);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn outcome_should_serialize_to_json() {
+        // Given
+        let verifier = create_verifier();
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+
+        // When
+        let valid = verifier.verify_outcome(&generate_key(12345));
+        let forged = verifier.verify_outcome(&forger.generate(12345));
+
+        // Then
+        assert_eq!(
+            r#"{"status":"valid"}"#,
+            serde_json::to_string(&valid).unwrap()
+        );
+        assert_eq!(
+            r#"{"status":"forged","reason":"byte_check","mismatch":{"ordinal":0,"actual":57}}"#,
+            serde_json::to_string(&forged).unwrap()
+        );
+    }
+
+    #[test]
+    pub fn all_key_formats_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+
+        for format in KeyFormat::all() {
+            // When
+            let result = format.deserialize(&format.serialize(&key));
+
+            // Then
+            assert_eq!(Ok(key.get_bytes()), result);
+        }
+    }
+
+    #[test]
+    pub fn all_key_formats_should_be_listed() {
+        // Given, When
+        let names: Vec<_> = KeyFormat::all().iter().map(|f| f.name()).collect();
+
+        // Then
+        assert_eq!(vec!["hex", "base32", "base58", "base64url"], names);
+    }
+
+    #[test]
+    pub fn correct_seed_should_pass_seed_checksum() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_seed_checksum(true);
+
+        // When
+        let key = generator.generate(12345);
+
+        // Then
+        assert!(key.seed_checksum_ok());
+        assert_eq!(Status::Valid, create_verifier().verify(&key));
+    }
+
+    #[test]
+    pub fn flipped_seed_bit_should_fail_seed_checksum() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_seed_checksum(true);
+        let key = generator.generate(12345);
+        let mut bytes = key.get_bytes();
+        bytes[7] ^= 0x01;
+
+        // When
+        let key = LicenseKey::new(bytes, key.layout());
+
+        // Then
+        assert!(!key.seed_checksum_ok());
+    }
+
+    #[test]
+    pub fn multi_verifier_should_accept_keys_valid_for_any_verifier() {
+        // Given
+        let old = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6)]);
+        let mut verifier = MultiVerifier::new();
+        verifier.add(create_verifier());
+        verifier.add(Verifier::new(
+            TestHasher::default(),
+            vec![ByteCheck::new(1, (4, 5, 6))],
+        ));
+
+        // When
+        let current = verifier.verify(&generate_key(12345));
+        let legacy = verifier.verify(&old.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, current);
+        assert_eq!(Status::Valid, legacy);
+    }
+
+    #[test]
+    pub fn multi_verifier_should_report_forged_keys() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(7, 8, 9), (7, 8, 9), (7, 8, 9)]);
+        let mut verifier = MultiVerifier::new();
+        verifier.add(create_verifier());
+        verifier.add(Verifier::new(
+            TestHasher::default(),
+            vec![ByteCheck::new(1, (4, 5, 6))],
+        ));
+
+        // When
+        let result = verifier.verify(&forger.generate(12345));
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn key_with_decoys_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.add_decoy(1, (9, 9, 9));
+        let verifier = Verifier::new(
+            TestHasher::default(),
+            vec![
+                ByteCheck::new(0, (114, 83, 170)),
+                ByteCheck::decoy(1, (9, 9, 9)),
+                ByteCheck::new(3, (69, 14, 202)),
+            ],
+        );
+
+        // When
+        let result = verifier.verify(&generator.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn key_with_wrong_decoys_should_be_forged() {
+        // Given
+        let cracked = Generator::new(
+            TestHasher::default(),
+            vec![
+                (114, 83, 170),
+                (9, 9, 9),
+                (60, 208, 27),
+                (69, 14, 202),
+                (61, 232, 54),
+            ],
+        );
+        let verifier = Verifier::new(
+            TestHasher::default(),
+            vec![
+                ByteCheck::new(0, (114, 83, 170)),
+                ByteCheck::decoy(1, (9, 9, 9)),
+                ByteCheck::new(3, (69, 14, 202)),
+            ],
+        );
+
+        // When
+        let result = verifier.verify(&cracked.generate(12345));
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn birthday_collision_probability_should_match_known_values() {
+        // Given, When
+        let single = birthday_collision_probability(64, 1);
+        let small = birthday_collision_probability(32, 77_163);
+        let large = birthday_collision_probability(64, 5_000_000_000);
+        let wide = birthday_collision_probability(128, 5_000_000_000);
+
+        // Then
+        assert_eq!(0.0, single);
+        assert!((small - 0.5).abs() < 0.001);
+        assert!((large - 0.49).abs() < 0.01);
+        assert!(wide < 1e-18);
+    }
+
+    #[test]
+    pub fn seed_within_blocked_range_should_be_blocked() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_range(1000, 2000);
+
+        // When
+        let inside = verifier.verify(&generate_key(2000));
+        let outside = verifier.verify(&generate_key(2001));
+
+        // Then
+        assert_eq!(Status::Blocked, inside);
+        assert_eq!(Status::Valid, outside);
+    }
+
+    #[test]
+    pub fn block_reason_should_be_retrievable() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_with_reason(12345, "chargeback".to_string());
+        verifier.block(67890);
+
+        // When
+        let status = verifier.verify(&generate_key(12345));
+
+        // Then
+        assert_eq!(Status::Blocked, status);
+        assert_eq!(Some("chargeback"), verifier.block_reason(12345));
+        assert_eq!(None, verifier.block_reason(67890));
+    }
+
+    #[test]
+    pub fn unblocked_seed_should_forget_block_reason() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_with_reason(12345, "refund".to_string());
+
+        // When
+        verifier.unblock(12345);
+
+        // Then
+        assert_eq!(None, verifier.block_reason(12345));
+    }
+
+    #[test]
+    pub fn seed_with_blocked_prefix_should_be_blocked() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_prefix(0xAB00_0000_0000_0000, 0xFF00_0000_0000_0000);
+
+        // When
+        let inside = verifier.verify(&generate_key(0xABFF_FFFF_FFFF_FFFF));
+        let outside = verifier.verify(&generate_key(0xAC00_0000_0000_0000));
+
+        // Then
+        assert_eq!(Status::Blocked, inside);
+        assert_eq!(Status::Valid, outside);
+    }
+
+    #[test]
+    pub fn seed_with_blocked_prefix_should_not_be_unblocked_individually() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_prefix(0xAB12_3456_7890_0000, 0xFF00_0000_0000_0000);
+
+        // When
+        let result = verifier.unblock(0xAB00_0000_0000_0001);
+
+        // Then
+        assert_eq!(
+            UnblockResult::BlockedByPrefix(0xAB00_0000_0000_0000, 0xFF00_0000_0000_0000),
+            result
+        );
+        assert!(verifier.is_blocked(0xAB00_0000_0000_0001));
+    }
+
+    #[test]
+    pub fn blocked_entries_should_contain_seeds_and_ranges() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.block(67890);
+        verifier.block_range(1000, 2000);
+
+        // When
+        let entries: Vec<_> = verifier.blocked_entries().collect();
+
+        // Then
+        assert_eq!(
+            vec![
+                BlockEntry::Seed(12345),
+                BlockEntry::Seed(67890),
+                BlockEntry::Range(1000, 2000),
+            ],
+            entries
+        );
+    }
+
+    #[test]
+    pub fn key_with_folded_length_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.fold_length_into_checksum(true);
+        let mut verifier = create_verifier();
+        verifier.fold_length_into_checksum(true);
+
+        // When
+        let result = verifier.verify(&generator.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn truncated_key_should_be_invalid_with_folded_length() {
+        // Given
+        let mut generator = create_generator();
+        generator.fold_length_into_checksum(true);
+        let mut verifier = create_verifier();
+        verifier.fold_length_into_checksum(true);
+
+        // Drop the last payload byte and recalculate the checksum
+        let bytes = generator.generate(12345).get_bytes();
+        let mut truncated = bytes[..bytes.len() - 3].to_vec();
+        truncated.extend_from_slice(&calculate_checksum(&truncated, false, false));
+        let truncated = LicenseKey::new(truncated, KeyLayout::default());
+
+        // When
+        let result = verifier.verify(&truncated);
+
+        // Then
+        assert_eq!(Status::Valid, create_verifier().verify(&truncated));
+        assert_eq!(Status::Invalid, result);
+    }
+
+    #[test]
+    pub fn checked_key_with_matching_verifier_should_be_ok() {
+        // Given
+        let generator = create_generator();
+        let verifier = create_verifier();
+
+        // When
+        let result = generator.generate_checked(12345, &verifier);
+
+        // Then
+        let bytes = result.map(|key| key.get_bytes());
+        assert_eq!(Ok(generate_key(12345).get_bytes()), bytes);
+    }
+
+    #[test]
+    pub fn checked_key_with_mismatched_verifier_should_be_err() {
+        // Given
+        let generator =
+            Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let verifier = create_verifier();
+
+        // When
+        let result = generator.generate_checked(12345, &verifier);
+
+        // Then
+        assert_eq!(Some(GenError::Verification(Status::Forged)), result.err());
+    }
+
+    #[test]
+    pub fn check_with_wrong_triplet_should_be_reported() {
+        // Given
+        let mut verifier = Verifier::new(
+            TestHasher::default(),
+            vec![
+                ByteCheck::new(0, (114, 83, 170)),
+                ByteCheck::new(1, (1, 2, 3)),
+                ByteCheck::new(2, (69, 14, 202)),
+            ],
+        );
+        verifier.expect_iv(vec![(114, 83, 170), (60, 208, 27), (69, 14, 202)]);
+
+        // When
+        let result = verifier.validate_checks_against_iv();
+
+        // Then
+        assert_eq!(Err(vec![1]), result);
+    }
+
+    #[test]
+    pub fn checks_matching_iv_should_be_ok() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.expect_iv(vec![(114, 83, 170), (60, 208, 27), (69, 14, 202)]);
+
+        // When
+        let result = verifier.validate_checks_against_iv();
+
+        // Then
+        assert_eq!(Ok(()), result);
+    }
+
+    #[test]
+    pub fn unblocking_blocked_seed_should_return_unblocked() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+
+        // When
+        let result = verifier.unblock(12345);
+
+        // Then
+        assert_eq!(UnblockResult::Unblocked, result);
+        assert_eq!(Status::Valid, verifier.verify(&generate_key(12345)));
+    }
+
+    #[test]
+    pub fn unblocking_seed_that_is_not_blocked_should_return_not_blocked() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(54321);
+
+        // When
+        let result = verifier.unblock(12345);
+
+        // Then
+        assert_eq!(UnblockResult::NotBlocked, result);
+    }
+
+    #[test]
+    pub fn unblocking_seed_in_blocked_range_should_return_blocked_by_range() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_with_reason(12345, "refund".to_string());
+        verifier.block_range(12000, 13000);
+
+        // When
+        let result = verifier.unblock(12345);
+
+        // Then
+        assert_eq!(UnblockResult::BlockedByRange(12000, 13000), result);
+        assert_eq!(Status::Blocked, verifier.verify(&generate_key(12345)));
+        assert_eq!(Some("refund"), verifier.block_reason(12345));
+        assert_eq!(vec![12345], verifier.export_blocklist());
+    }
+
+    #[test]
+    pub fn blocked_key_should_decode_but_be_decided_as_blocked() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+
+        // When
+        let decoded = verifier.decode(&key).unwrap();
+        let result = verifier.decide(&decoded, 0);
+
+        // Then
+        assert_eq!(12345, decoded.seed);
+        assert_eq!(Status::Blocked, result);
+    }
+
+    #[test]
+    pub fn key_with_bad_checksum_should_not_decode() {
+        // Given
+        let mut bytes = generate_key(12345).get_bytes();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        let key = LicenseKey::new(bytes, KeyLayout::default());
+
+        // When
+        let result = create_verifier().decode(&key);
+
+        // Then
+        assert_eq!(Some(Reason::Checksum), result.err());
+    }
+
+    #[test]
+    pub fn key_with_expired_claims_should_be_decided_as_expired() {
+        // Given
+        let claims = Claims {
+            expires_at: 1000,
+            features: 0,
+            product: 1,
+        };
+        let fields = KeyFields {
+            claims: Some((claims, [0; SIGNATURE_BYTE_LENGTH as usize])),
+            ..KeyFields::default()
+        };
+        let key = create_generator().generate_with_fields(12345, fields);
+        let verifier = create_verifier();
+        let decoded = verifier.decode(&key).unwrap();
+
+        // When
+        let result = verifier.decide(&decoded, 1001);
+
+        // Then
+        assert_eq!(Status::Valid, verifier.decide(&decoded, 1000));
+        assert_eq!(Status::Expired, result);
+    }
+
+    #[test]
+    pub fn key_with_claims_that_never_expire_should_be_valid() {
+        // Given
+        let claims = Claims {
+            expires_at: 0,
+            features: 0,
+            product: 1,
+        };
+        let fields = KeyFields {
+            claims: Some((claims, [0; SIGNATURE_BYTE_LENGTH as usize])),
+            ..KeyFields::default()
+        };
+        let key = create_generator().generate_with_fields(12345, fields);
+        let mut verifier = create_verifier();
+        verifier.use_clock(FixedClock(1000));
+
+        // When
+        let result = verifier.verify(&key);
+        let mut stream = verifier.stream_with_layout(key.layout());
+        stream.feed(key.as_bytes());
+
+        // Then
+        assert_eq!(Status::Valid, result);
+        assert_eq!(Status::Valid, verifier.verify_at(&key, 1000));
+        assert_eq!(Status::Valid, stream.finish());
+    }
+
+    #[test]
+    pub fn region_key_should_be_valid_in_matching_region() {
+        // Given
+        let key = create_generator().generate_region_key(12345, 46);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_for_region(&key, 46);
+
+        // Then
+        assert_eq!(Some(46), key.region());
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn region_key_should_not_be_valid_in_other_region() {
+        // Given
+        let key = create_generator().generate_region_key(12345, 46);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_for_region(&key, 47);
+
+        // Then
+        assert_eq!(Status::WrongRegion, result);
+    }
+
+    #[test]
+    pub fn key_without_region_should_be_valid_in_every_region() {
+        // Given
+        let key = generate_key(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_for_region(&key, 47);
+
+        // Then
+        assert_eq!(None, key.region());
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn region_key_with_altered_region_should_be_forged() {
+        // Given
+        let key = create_generator().generate_region_key(12345, 46);
+        let mut bytes = key.get_bytes();
+        bytes[9] = 47;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let key = LicenseKey::new(bytes, key.layout());
+
+        // When
+        let result = create_verifier().verify_for_region(&key, 47);
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn sequence_seeds_should_be_distinct_and_reproducible() {
+        // Given
+        let secret = b"secret";
+
+        // When
+        let seeds: Vec<u64> = (0..10_000).map(|n| sequence_seed(n, secret)).collect();
+
+        // Then
+        let unique: std::collections::HashSet<_> = seeds.iter().collect();
+        assert_eq!(seeds.len(), unique.len());
+        assert_eq!(seeds[42], sequence_seed(42, secret));
+        assert_ne!(seeds[42], sequence_seed(42, b"other"));
+        assert_ne!(1, seeds[1]);
+    }
+
+    #[test]
+    pub fn hex_should_deserialize_mixed_case_like_canonical_case() {
+        // Given
+        let canonical = generate_key(12345).serialize(&HexFormat);
+        let mixed: String = canonical
+            .chars()
+            .enumerate()
+            .map(|(index, c)| match index % 2 {
+                0 => c.to_ascii_uppercase(),
+                _ => c,
+            })
+            .collect();
+
+        // When
+        let result = HexFormat.deserialize(&mixed);
+
+        // Then
+        assert!(KeyFormat::Hex.case_insensitive());
+        assert_eq!(HexFormat.deserialize(&canonical), result);
+    }
+
+    #[test]
+    pub fn versioned_key_should_return_version() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_version(2);
+        let key = generator.generate(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_version(&key);
+
+        // Then
+        assert_eq!((Status::Valid, Some(2)), result);
+    }
+
+    #[test]
+    pub fn key_without_version_should_not_return_version() {
+        // Given
+        let key = generate_key(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_version(&key);
+
+        // Then
+        assert_eq!((Status::Valid, None), result);
+    }
+
+    #[test]
+    pub fn padded_keys_should_have_same_length_and_be_valid() {
+        // Given
+        let mut short = Generator::new(
+            TestHasher::default(),
+            vec![(114, 83, 170), (60, 208, 27), (69, 14, 202)],
+        );
+        short.pad_payload(8);
+        let mut long = create_generator();
+        long.pad_payload(8);
+        let verifier = create_verifier();
+
+        // When
+        let short_key = short.generate(12345);
+        let long_key = long.generate(12345);
+
+        // Then
+        let short_hex = short_key.serialize(&HexFormat);
+        let long_hex = long_key.serialize(&HexFormat);
+        assert_eq!(KeyLayout::default().key_length(8) * 2, short_hex.len());
+        assert_eq!(short_hex.len(), long_hex.len());
+        assert_eq!(Status::Valid, verifier.verify(&short_key));
+        assert_eq!(Status::Valid, verifier.verify(&long_key));
+    }
+
+    #[test]
+    pub fn generator_with_published_iv_should_be_rejected() {
+        // Given
+        let iv = vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)];
+
+        // When
+        let result = Generator::try_new(TestHasher::default(), iv);
+
+        // Then
+        assert_eq!(Some(GenError::PublishedIv), result.err());
+    }
+
+    #[test]
+    pub fn generator_with_empty_iv_should_be_rejected() {
+        // Given, When
+        let result = Generator::try_new(TestHasher::default(), Vec::new());
+
+        // Then
+        assert_eq!(Some(GenError::EmptyIv), result.err());
+    }
+
+    #[test]
+    pub fn verifier_with_out_of_range_ordinal_should_be_rejected() {
+        // Given
+        let generator = create_generator();
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(4, (69, 14, 202)),
+        ];
+
+        // When
+        let result = Verifier::try_new(TestHasher::default(), checks, generator.payload_length());
+
+        // Then
+        let expected = VerifierError::OrdinalOutOfRange {
+            ordinal: 4,
+            payload_length: 4,
+        };
+        assert_eq!(Some(expected), result.err());
+    }
+
+    #[test]
+    pub fn verifier_with_ordinals_in_range_should_be_created() {
+        // Given
+        let generator = create_generator();
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(2, (69, 14, 202)),
+        ];
+
+        // When
+        let verifier =
+            Verifier::try_new(TestHasher::default(), checks, generator.payload_length()).unwrap();
+
+        // Then
+        assert_eq!(Some(4), verifier.payload_length());
+        assert_eq!(Status::Valid, verifier.verify(&generate_key(12345)));
+    }
+
+    #[test]
+    pub fn key_without_payload_should_be_missing_bytes() {
+        // Given
+        let key = Generator::new(TestHasher::default(), Vec::new()).generate(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_outcome(&key);
+
+        // Then
+        assert_eq!(10, key.get_bytes().len());
+        assert_eq!(Status::Invalid, result.status);
+        assert_eq!(Some(Reason::MissingByte), result.reason);
+    }
+
+    #[test]
+    pub fn generator_with_own_iv_should_be_accepted() {
+        // Given
+        let iv = vec![(1, 2, 3), (4, 5, 6)];
+
+        // When
+        let result = Generator::try_new(TestHasher::default(), iv);
+
+        // Then
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    pub fn verify_failures_should_only_return_failing_keys() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let keys = [
+            generate_key(1),
+            forger.generate(2),
+            generate_key(3),
+            generate_key(4),
+        ];
+        let mut verifier = create_verifier();
+        verifier.block(4);
+
+        // When
+        let result: Vec<_> = verifier
+            .verify_failures(keys.iter())
+            .map(|(key, status)| (key.seed().unwrap(), status))
+            .collect();
+
+        // Then
+        assert_eq!(vec![(2, Status::Forged), (4, Status::Blocked)], result);
+    }
+
+    #[test]
+    pub fn parsing_empty_input_should_return_error() {
+        // Given, When
+        let result = LicenseKey::parse("", &HexFormat);
+
+        // Then
+        assert_eq!(Some(DeserializeError::Empty), result.err());
+    }
+
+    #[test]
+    pub fn parsing_invalid_character_should_return_error() {
+        // Given, When
+        let result = LicenseKey::parse("1122X0F4", &HexFormat);
+
+        // Then
+        let expected = DeserializeError::InvalidCharacter {
+            character: 'X',
+            index: 4,
+        };
+        assert_eq!(Some(expected), result.err());
+    }
+
+    #[test]
+    pub fn parsing_odd_length_should_return_error() {
+        // Given, When
+        let result = LicenseKey::parse("112", &HexFormat);
+
+        // Then
+        assert_eq!(Some(DeserializeError::OddLength), result.err());
+    }
+
+    #[test]
+    pub fn parsing_too_short_key_should_return_error() {
+        for input in ["00", "001122334455667788"].iter() {
+            // Given, When
+            let result = LicenseKey::parse(input, &HexFormat);
+
+            // Then
+            let expected = DeserializeError::TooShort {
+                length: input.len() / 2,
+                minimum: SeedLength::Eight.bytes() + CHECKSUM_BYTE_LENGTH as usize,
+            };
+            assert_eq!(Some(expected), result.err());
+        }
+    }
+
+    #[test]
+    pub fn key_should_round_trip_through_raw_bytes() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_800_000_000);
+        let layout = KeyLayout {
+            expiry: true,
+            ..KeyLayout::default()
+        };
+
+        // When
+        let result = LicenseKey::from_bytes_with_layout(key.as_bytes(), layout).unwrap();
+
+        // Then
+        assert_eq!(key, result);
+        assert_eq!(Some(1_800_000_000), result.expires_at());
+        assert_eq!(Status::Valid, create_verifier().verify(&result));
+    }
+
+    #[test]
+    pub fn key_should_round_trip_through_parts() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_800_000_000);
+        let expected = key.get_bytes();
+
+        // When
+        let (bytes, layout) = key.clone().into_parts();
+        let result = LicenseKey::from_parts(bytes, layout).unwrap();
+
+        // Then
+        assert_eq!(key, result);
+        assert_eq!(Some(1_800_000_000), result.expires_at());
+        assert_eq!(expected, result.into_bytes());
+    }
+
+    #[test]
+    pub fn parts_too_short_for_layout_should_return_error() {
+        // Given
+        let layout = KeyLayout {
+            expiry: true,
+            ..KeyLayout::default()
+        };
+
+        // When
+        let short = LicenseKey::from_parts(vec![0; 12], layout);
+        let empty = LicenseKey::from_parts(Vec::new(), layout);
+
+        // Then
+        let expected = DeserializeError::TooShort {
+            length: 12,
+            minimum: 14,
+        };
+        assert_eq!(Some(expected), short.err());
+        assert_eq!(Some(DeserializeError::Empty), empty.err());
+    }
+
+    #[test]
+    pub fn raw_bytes_too_short_for_layout_should_return_error() {
+        // Given
+        let bytes = generate_key(12345).get_bytes();
+        let layout = KeyLayout {
+            expiry: true,
+            claims: true,
+            ..KeyLayout::default()
+        };
+
+        // When
+        let result = LicenseKey::from_bytes_with_layout(&bytes, layout);
+
+        // Then
+        let expected = DeserializeError::TooShort {
+            length: bytes.len(),
+            minimum: layout.key_length(0),
+        };
+        assert_eq!(Some(expected), result.err());
+        assert_eq!(
+            Some(DeserializeError::Empty),
+            LicenseKey::from_bytes(&[]).err()
+        );
+    }
+
+    #[test]
+    pub fn parsing_minimal_key_should_succeed() {
+        // Given
+        let input = "0011223344556677889a";
+
+        // When
+        let result = LicenseKey::parse(input, &HexFormat).unwrap();
+
+        // Then
+        assert_eq!(Some(0x0011223344556677), result.seed());
+        assert_eq!(Some(&[][..]), result.payload());
+    }
+
+    #[test]
+    pub fn blocked_then_unblocked_key_should_be_valid() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.block(12345);
+
+        // When
+        verifier.unblock(12345);
+
+        // Then
+        assert_eq!(Status::Valid, verifier.verify(&key));
+        assert_eq!(UnblockResult::NotBlocked, verifier.unblock(12345));
+    }
+
+    #[test]
+    pub fn blocked_seeds_should_yield_exactly_the_blocked_seeds() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(67890);
+        verifier.block(12345);
+        verifier.block(54321);
+        verifier.unblock(54321);
+
+        // When
+        let seeds: Vec<_> = verifier.blocked_seeds().collect();
+
+        // Then
+        assert_eq!(vec![12345, 67890], seeds);
+    }
+
+    #[test]
+    pub fn is_blocked_should_check_seeds_and_ranges() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.block_range(1000, 2000);
+
+        // When, Then
+        assert!(verifier.is_blocked(12345));
+        assert!(verifier.is_blocked(1500));
+        assert!(!verifier.is_blocked(54321));
+    }
+
+    #[test]
+    pub fn is_seed_blocked_should_check_seeds_of_same_length() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_seed(5u32);
+        verifier.block_wide(7);
+        verifier.block_range(1000, 2000);
+
+        // When, Then
+        assert!(verifier.is_seed_blocked(5u32));
+        assert!(!verifier.is_seed_blocked(5u64));
+        assert!(verifier.is_seed_blocked(7u128));
+        assert!(!verifier.is_seed_blocked(7u64));
+        assert!(verifier.is_seed_blocked(1500u64));
+        assert!(!verifier.is_seed_blocked(1500u32));
+    }
+
+    #[test]
+    pub fn is_device_blocked_should_check_devices_and_seeds() {
+        // Given
+        let mut verifier = create_verifier();
+        verifier.block_device(12345, 2);
+        verifier.block(67890);
+
+        // When, Then
+        assert!(verifier.is_device_blocked(12345, 2));
+        assert!(!verifier.is_device_blocked(12345, 3));
+        assert!(!verifier.is_blocked(12345));
+        assert!(verifier.is_device_blocked(67890, 3));
+    }
+
+    #[test]
+    pub fn imported_blocklist_should_be_merged() {
+        // Given
+        let mut exporter = create_verifier();
+        exporter.block(12345);
+        exporter.block(67890);
+        let mut verifier = create_verifier();
+        verifier.block(12345);
+        verifier.block(54321);
+
+        // When
+        verifier.import_blocklist(exporter.export_blocklist());
+        verifier.import_blocklist(exporter.export_blocklist());
+
+        // Then
+        assert_eq!(vec![12345, 54321, 67890], verifier.export_blocklist());
+        assert_eq!(Status::Blocked, verifier.verify(&generate_key(67890)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    pub fn license_key_should_round_trip_through_json() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let json = serde_json::to_string(&key).unwrap();
+        let result: LicenseKey = serde_json::from_str(&json).unwrap();
+
+        // Then
+        assert_eq!(format!("\"{}\"", key.serialize(&HexFormat)), json);
+        assert_eq!(key.get_bytes(), result.get_bytes());
+    }
+
+    #[test]
+    pub fn boxed_hasher_chosen_at_runtime_should_work() {
+        // Given
+        struct OtherHasher {}
+        impl KeyHasher for OtherHasher {
+            fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+                (seed.wrapping_add(a) ^ b ^ c) as u8
+            }
+        }
+        let create_hasher = |name: &str| -> Box<dyn KeyHasher> {
+            match name {
+                "test" => Box::new(TestHasher::default()),
+                _ => Box::new(OtherHasher {}),
+            }
+        };
+        let iv = vec![(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)];
+        let generator = Generator::new(create_hasher("test"), iv);
+        let verifier = Verifier::new(
+            create_hasher("other"),
+            vec![ByteCheck::new(0, (114, 83, 170))],
+        );
+
+        // When
+        let key = generator.generate(12345);
+
+        // Then
+        assert_eq!(generate_key(12345).get_bytes(), key.get_bytes());
+        assert_eq!(Status::Forged, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn key_with_long_checksum_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_long_checksum(true);
+        let verifier = create_verifier();
+
+        // When
+        let key = generator.generate(12345);
+        let parsed = LicenseKey::new(
+            key.get_bytes(),
+            KeyLayout {
+                long_checksum: true,
+                ..KeyLayout::default()
+            },
+        );
+
+        // Then
+        assert_eq!(
+            generate_key(12345).get_bytes().len() + 2,
+            key.get_bytes().len()
+        );
+        assert_eq!(Status::Valid, verifier.verify(&key));
+        assert_eq!(Status::Valid, verifier.verify(&parsed));
+    }
+
+    #[test]
+    pub fn truncated_long_checksum_should_be_invalid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_long_checksum(true);
+        let verifier = create_verifier();
+        let bytes = generator.generate(12345).get_bytes();
+
+        // When
+        let truncated = LicenseKey::new(
+            bytes[..bytes.len() - 2].to_vec(),
+            KeyLayout {
+                long_checksum: true,
+                ..KeyLayout::default()
+            },
+        );
+        let short = LicenseKey::new(bytes, KeyLayout::default());
+
+        // Then
+        assert_eq!(Status::Invalid, verifier.verify(&truncated));
+        assert_eq!(Status::Invalid, verifier.verify(&short));
+    }
+
+    #[test]
+    pub fn key_with_wide_seed_should_round_trip() {
+        // Given
+        let seed = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210_u128;
+        let layout = KeyLayout {
+            seed_length: SeedLength::Sixteen,
+            ..KeyLayout::default()
+        };
+        let verifier = create_verifier();
+
+        // When
+        let key = create_generator().generate_wide(seed);
+        let parsed =
+            LicenseKey::parse_with_layout(&key.serialize(&HexFormat), &HexFormat, layout).unwrap();
+
+        // Then
+        assert_eq!(Some(seed), parsed.wide_seed());
+        assert_eq!(Status::Valid, verifier.verify(&key));
+        assert_eq!(Status::Valid, verifier.verify(&parsed));
+    }
+
+    #[test]
+    pub fn wide_seeds_with_same_low_bits_should_differ() {
+        // Given
+        let generator = create_generator();
+
+        // When
+        let first = generator.generate_wide(12345);
+        let second = generator.generate_wide((1_u128 << 64) | 12345);
+
+        // Then
+        assert_ne!(first.wide_seed(), second.wide_seed());
+        assert_ne!(first.get_bytes(), second.get_bytes());
+    }
+
+    #[test]
+    pub fn wide_seeds_with_same_halves_should_have_different_payloads() {
+        // Given
+        let generator = create_generator();
+        let seeds = [(1_u128 << 64) | 12345, (12345_u128 << 64) | 1, 12344];
+
+        // When
+        let payloads: Vec<Vec<u8>> = seeds
+            .iter()
+            .map(|seed| generator.generate_wide(*seed).payload().unwrap().to_vec())
+            .collect();
+
+        // Then
+        assert_ne!(payloads[0], payloads[1]);
+        assert_ne!(payloads[0], payloads[2]);
+        assert_ne!(payloads[1], payloads[2]);
+    }
+
+    #[test]
+    #[cfg(feature = "hmac")]
+    pub fn hmac_hasher_should_fold_wide_seeds_with_secret() {
+        // Given
+        let seed = ((1_u128 << 64) | 12345).to_be_bytes();
+        let hasher = HmacHasher::new(b"secret");
+
+        // When
+        let folded = hasher.fold_seed(&seed);
+
+        // Then
+        assert_ne!(HmacHasher::new(b"other").fold_seed(&seed), folded);
+        assert_ne!(hasher.fold_seed(&12344_u128.to_be_bytes()), folded);
+    }
+
+    #[test]
+    pub fn wide_key_should_only_be_blocked_by_its_full_seed() {
+        // Given
+        let seed = (1_u128 << 64) | 12344;
+        let key = create_generator().generate_wide(seed);
+        let mut verifier = create_verifier();
+
+        // When
+        verifier.block(12344);
+        verifier.block(1 ^ 12344);
+        verifier.block_range(0, u64::MAX);
+        let unblocked = verifier.verify(&key);
+        verifier.block_wide(seed);
+        let blocked = verifier.verify_outcome(&key);
+
+        // Then
+        assert_eq!(Status::Valid, unblocked);
+        assert_eq!(Status::Blocked, blocked.status);
+        assert_eq!(BlockEntry::WideSeed(seed), blocked.blocked.unwrap().entry);
+        assert_eq!(UnblockResult::Unblocked, verifier.unblock_wide(seed));
+        assert_eq!(Status::Valid, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn seed_from_str_should_be_stable() {
+        // Given, When
+        let empty = seed_from_str("");
+        let single = seed_from_str("a");
+        let word = seed_from_str("foobar");
+
+        // Then
+        assert_eq!(0xCBF2_9CE4_8422_2325, empty);
+        assert_eq!(0xAF63_DC4C_8601_EC8C, single);
+        assert_eq!(0x8594_4171_F739_67E8, word);
+        assert_eq!(seed_from_bytes(b"foobar"), word);
+    }
+
+    #[test]
+    pub fn constant_time_comparisons_should_match_regular_comparisons() {
+        for (a, b) in [(0_u8, 0_u8), (0, 1), (0x80, 0), (0xFF, 0xFF), (0xFF, 0xFE)].iter() {
+            // When
+            let mismatch = constant_time_mismatch(*a as u32, *b as u32);
+            let equal = constant_time_eq(&[1, *a], &[1, *b]);
+
+            // Then
+            assert_eq!((a != b) as usize, mismatch);
+            assert_eq!(a == b, equal);
+        }
+        assert!(!constant_time_eq(&[1, 2], &[1]));
+    }
+
+    #[test]
+    pub fn serializers_chosen_at_runtime_should_round_trip() {
+        // Given
+        let key = generate_key(12345);
+        let formats: Vec<Box<dyn Serializer>> = vec![
+            Box::new(HexFormat),
+            Box::new(Grouped::new(Base32Format, 4, ' ')),
+            Box::new(CrcGroupFormat::new(Grouped::new(HexFormat, 6, '.'))),
+        ];
+
+        for format in formats.iter() {
+            // When
+            let result = LicenseKey::parse(&key.serialize(format.as_ref()), format.as_ref());
+
+            // Then
+            assert_eq!(key.get_bytes(), result.unwrap().get_bytes());
+        }
+    }
+
+    #[test]
+    pub fn key_with_expiry_should_expire() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_800_000_000);
+        let verifier = create_verifier();
+
+        // When
+        let before = verifier.verify_at(&key, 1_799_999_999);
+        let after = verifier.verify_at(&key, 1_800_000_001);
+
+        // Then
+        assert_eq!(Some(1_800_000_000), key.expires_at());
+        assert_eq!(Status::Valid, before);
+        assert_eq!(Status::Expired, after);
+    }
+
+    #[test]
+    pub fn key_with_altered_expiry_should_be_forged() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_800_000_000);
+        let mut bytes = key.get_bytes();
+        bytes[8..12].copy_from_slice(&1_900_000_123_u32.to_be_bytes());
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let key = LicenseKey::new(bytes, key.layout());
+
+        // When
+        let result = create_verifier().verify_at(&key, 1_850_000_000);
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    fn flags_layout() -> KeyLayout {
+        KeyLayout {
+            flags: true,
+            expiry: true,
+            ..KeyLayout::default()
+        }
+    }
+
+    #[test]
+    pub fn paid_and_trial_keys_should_be_verified_by_same_verifier() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_flags(true);
+        let paid = generator
+            .generate_with_expiry(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let trial = generator
+            .generate_trial(67890, 1_800_000_000)
+            .serialize(&HexFormat);
+        let verifier = create_verifier();
+
+        // When
+        let paid = LicenseKey::parse_with_layout(&paid, &HexFormat, flags_layout()).unwrap();
+        let trial = LicenseKey::parse_with_layout(&trial, &HexFormat, flags_layout()).unwrap();
+        let now = 1_800_000_000 - 3 * 86_400 + 1;
+
+        // Then
+        assert!(!paid.is_trial());
+        assert!(trial.is_trial());
+        assert_eq!(Status::Valid, verifier.verify_at(&paid, now));
+        assert_eq!(
+            Status::Trial { days_remaining: 3 },
+            verifier.verify_at(&trial, now)
+        );
+        assert_eq!(Status::Expired, verifier.verify_at(&trial, 1_800_000_001));
+        assert_eq!(Status::Expired, verifier.verify_at(&paid, 1_800_000_001));
+    }
+
+    #[test]
+    pub fn trial_key_verified_without_clock_should_be_expired() {
+        // Given
+        let key = create_generator().generate_trial(12345, 1_800_000_000);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify(&key);
+        let mut stream = verifier.stream_with_layout(key.layout());
+        stream.feed(key.as_bytes());
+
+        // Then
+        assert_eq!(Status::Expired, result);
+        assert_eq!(Status::Expired, stream.finish());
+        assert_eq!(Err(LicenseError::Expired), verifier.check(&key));
+    }
+
+    #[test]
+    pub fn trial_key_with_cleared_trial_flag_should_be_forged() {
+        // Given
+        let key = create_generator().generate_trial(12345, 1_800_000_000);
+        let mut bytes = key.get_bytes();
+        bytes[key.layout().flags_offset()] &= !TRIAL_FLAG;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let paid = LicenseKey::new(bytes, key.layout());
+
+        // When
+        let result = create_verifier().verify_at(&paid, 1_700_000_000);
+
+        // Then
+        assert!(!paid.is_trial());
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn key_with_issue_date_should_return_issue_date() {
+        // Given
+        let key = create_generator().generate_with_issue_date(12345, 1_700_000_000);
+        let layout = KeyLayout {
+            issue_date: true,
+            ..KeyLayout::default()
+        };
+
+        // When
+        let parsed =
+            LicenseKey::parse_with_layout(&key.serialize(&HexFormat), &HexFormat, layout).unwrap();
+
+        // Then
+        assert_eq!(Status::Valid, create_verifier().verify(&parsed));
+        assert_eq!(Some(1_699_920_000), parsed.issued_at());
+        assert_eq!(None, generate_key(12345).issued_at());
+    }
+
+    #[test]
+    pub fn key_with_altered_issue_date_should_be_forged() {
+        // Given
+        let key = create_generator().generate_with_issue_date(12345, 1_700_000_000);
+        let mut bytes = key.get_bytes();
+        bytes[9] ^= 0x01;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let key = LicenseKey::new(bytes, key.layout());
+
+        // When
+        let result = create_verifier().verify(&key);
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn key_too_short_for_expiry_should_be_invalid() {
+        // Given
+        let layout = KeyLayout {
+            expiry: true,
+            ..KeyLayout::default()
+        };
+        let key = LicenseKey::new(generate_key(12345).get_bytes()[..10].to_vec(), layout);
+
+        // When
+        let result = create_verifier().verify_at(&key, 0);
+
+        // Then
+        assert_eq!(None, key.expires_at());
+        assert_eq!(Status::Invalid, result);
+    }
+
+    #[test]
+    pub fn well_formed_key_should_expose_seed_and_payload() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let seed = key.seed();
+        let payload = key.payload();
+
+        // Then
+        assert_eq!(Some(12345), seed);
+        let expected = create_generator().expected_payload(12345);
+        assert_eq!(Some(&expected[..]), payload);
+    }
+
+    #[test]
+    pub fn truncated_key_should_not_expose_seed_or_payload() {
+        // Given
+        let bytes = generate_key(12345).get_bytes();
+        let short = LicenseKey::new(bytes[..9].to_vec(), KeyLayout::default());
+        let shorter = LicenseKey::new(bytes[..7].to_vec(), KeyLayout::default());
+
+        // When, Then
+        assert_eq!(Some(12345), short.seed());
+        assert_eq!(None, short.payload());
+        assert_eq!(None, shorter.seed());
+        assert_eq!(None, shorter.wide_seed());
+    }
+
+    #[test]
+    pub fn single_triplet_key_should_expose_its_only_payload_byte() {
+        // Given
+        let generator = Generator::new(TestHasher::default(), vec![(114, 83, 170)]);
+        let verifier = Verifier::new(
+            TestHasher::default(),
+            vec![ByteCheck::new(0, (114, 83, 170))],
+        );
+        let key = generator.generate(12345);
+
+        // When
+        let first = key.get_segment(0);
+        let past_end = key.get_segment(1);
+
+        // Then
+        assert_eq!(11, key.get_bytes().len());
+        assert_eq!(Some(&key.get_bytes()[8..9]), first);
+        assert_eq!(None, past_end);
+        assert_eq!(Status::Valid, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn first_middle_and_last_payload_bytes_should_be_checked() {
+        // Given
+        let key = generate_key(12345);
+        let bytes = key.get_bytes();
+        let iv = [(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)];
+
+        for ordinal in [0_u8, 1, 3].iter() {
+            let iv = iv[*ordinal as usize];
+            let verifier = Verifier::new(TestHasher::default(), vec![ByteCheck::new(*ordinal, iv)]);
+
+            // When
+            let segment = key.get_segment(*ordinal as usize);
+            let result = verifier.verify(&key);
+
+            // Then
+            let index = 8 + *ordinal as usize;
+            assert_eq!(Some(&bytes[index..index + 1]), segment);
+            assert_eq!(Status::Valid, result);
+        }
+        let truncated = LicenseKey::new(vec![1], KeyLayout::default());
+        assert_eq!(None, key.get_segment(4));
+        assert_eq!(None, truncated.get_segment(0));
+    }
+
+    #[test]
+    pub fn generated_string_should_verify() {
+        // Given
+        let generator = create_generator();
+        let verifier = create_verifier();
+
+        // When
+        let good = verifier.verify_str(&generator.generate_str(12345, &HexFormat), &HexFormat);
+        let garbage = verifier.verify_str("not a license key", &HexFormat);
+
+        // Then
+        assert_eq!(Status::Valid, good);
+        assert_eq!(Status::Invalid, garbage);
+    }
+
+    #[test]
+    pub fn versioned_verifier_should_route_by_version() {
+        // Given
+        let mut old = create_generator();
+        old.include_version(1);
+        let mut new = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        new.include_version(2);
+        let mut verifier = VersionedVerifier::new();
+        verifier.add(1, create_verifier());
+        verifier.add(
+            2,
+            Verifier::new(TestHasher::default(), vec![ByteCheck::new(1, (4, 5, 6))]),
+        );
+
+        // When
+        let v1 = verifier.verify(&old.generate(12345));
+        let v2 = verifier.verify(&new.generate(12345));
+
+        // Then
+        assert_eq!(Status::Valid, v1);
+        assert_eq!(Status::Valid, v2);
+    }
+
+    #[test]
+    pub fn unregistered_version_should_be_unsupported() {
+        // Given
+        let mut generator = create_generator();
+        generator.include_version(3);
+        let mut verifier = VersionedVerifier::new();
+        verifier.add(1, create_verifier());
+
+        // When
+        let unregistered = verifier.verify(&generator.generate(12345));
+        let unversioned = verifier.verify(&generate_key(12345));
+
+        // Then
+        assert_eq!(Status::UnsupportedVersion, unregistered);
+        assert_eq!(Status::UnsupportedVersion, unversioned);
+    }
+
+    #[test]
+    pub fn key_should_round_trip_through_display_and_from_str() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result: LicenseKey = key.to_string().parse().unwrap();
+
+        // Then
+        assert_eq!(key.serialize(&HexFormat), key.to_string());
+        assert_eq!(key.get_bytes(), result.get_bytes());
+    }
+
+    #[test]
+    pub fn invalid_string_should_not_parse_into_key() {
+        // Given, When
+        let result = "XY".parse::<LicenseKey>();
+
+        // Then
+        let expected = DeserializeError::InvalidCharacter {
+            character: 'X',
+            index: 0,
+        };
+        assert_eq!(Some(expected), result.err());
+    }
+
+    #[test]
+    #[cfg(feature = "zeroize")]
+    pub fn zeroized_key_should_be_cleared() {
+        // Given
+        use zeroize::Zeroize;
+        let mut key = generate_key(12345);
+
+        // When
+        key.zeroize();
+
+        // Then
+        assert!(key.as_bytes().is_empty());
+        assert_eq!(None, key.seed());
+    }
+
+    #[test]
+    pub fn as_bytes_should_match_get_bytes() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = key.as_bytes();
+
+        // Then
+        assert_eq!(key.get_bytes(), result);
+    }
+
+    #[test]
+    pub fn batch_should_match_generating_in_a_loop() {
+        // Given
+        let generator = create_generator();
+        let seeds = vec![1, 12345, 98765, u64::MAX];
+
+        // When
+        let result = generator.generate_batch(seeds.clone());
+
+        // Then
+        let expected: Vec<Vec<u8>> = seeds.iter().map(|s| generate_key(*s).get_bytes()).collect();
+        let actual: Vec<Vec<u8>> = result.iter().map(|key| key.get_bytes()).collect();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    pub fn iterator_should_generate_keys_lazily() {
+        // Given
+        let generator = create_generator();
+
+        // When
+        let result: Vec<LicenseKey> = generator.generate_iter(0..).take(3).collect();
+
+        // Then
+        assert_eq!(3, result.len());
+        assert_eq!(generate_key(2).get_bytes(), result[2].get_bytes());
+    }
+
+    #[test]
+    pub fn verify_many_should_preserve_order() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let keys = vec![generate_key(1), forger.generate(2), generate_key(3)];
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_many(keys);
+
+        // Then
+        assert_eq!(vec![Status::Valid, Status::Forged, Status::Valid], result);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    pub fn parallel_verification_should_equal_serial_verification() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let keys: Vec<LicenseKey> = (0..100)
+            .map(|seed| match seed % 3 {
+                0 => forger.generate(seed),
+                _ => generate_key(seed),
+            })
+            .collect();
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_many_parallel(&keys);
+
+        // Then
+        assert_eq!(verifier.verify_many(keys), result);
+    }
+
+    #[test]
+    pub fn check_should_return_error_matching_status() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let mut verifier = create_verifier();
+        verifier.block(67890);
+        let mut invalid = generate_key(12345).get_bytes();
+        invalid[0] ^= 0x01;
+
+        // When
+        let valid = verifier.check(&generate_key(12345));
+        let invalid = verifier.check(&LicenseKey::new(invalid, KeyLayout::default()));
+        let blocked = verifier.check(&generate_key(67890));
+        let forged = verifier.check(&forger.generate(12345));
+
+        // Then
+        assert_eq!(Ok(()), valid);
+        assert_eq!(Err(LicenseError::Invalid), invalid);
+        assert_eq!(Err(LicenseError::Blocked), blocked);
+        assert_eq!(Err(LicenseError::Forged), forged);
+    }
+
+    #[test]
+    pub fn check_should_return_expired_error() {
+        // Given
+        let key = create_generator().generate_with_expiry(12345, 1_700_000_000);
+        let mut verifier = create_verifier();
+        verifier.use_clock(FixedClock(1_800_000_000));
+
+        // When
+        let result = verifier.check(&key);
+
+        // Then
+        assert_eq!(Some(LicenseError::Expired), result.err());
+    }
+
+    #[test]
+    pub fn license_error_should_have_message() {
+        // Given, When
+        let result = LicenseError::Forged.to_string();
+
+        // Then
+        assert_eq!("the license key has been forged", result);
+    }
+
+    #[test]
+    pub fn key_with_correct_checksum_should_pass_checksum_verification() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let result = key.verify_checksum();
+
+        // Then
+        assert!(result);
+    }
+
+    #[test]
+    pub fn key_with_corrupt_payload_should_fail_checksum_verification() {
+        // Given
+        let mut bytes = generate_key(12345).get_bytes();
+        bytes[8] ^= 0x01;
+        let key = LicenseKey::new(bytes, KeyLayout::default());
+
+        // When
+        let result = key.verify_checksum();
+
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    pub fn too_short_key_should_fail_checksum_verification() {
+        // Given
+        let key = LicenseKey::new(vec![0x12, 0x34], KeyLayout::default());
+
+        // When
+        let result = key.verify_checksum();
+
+        // Then
+        assert!(!result);
+    }
+
+    #[test]
+    pub fn key_with_wide_segments_should_be_valid() {
+        for segment_length in [SegmentLength::Two, SegmentLength::Four].iter() {
+            // Given
+            let mut generator = create_generator();
+            generator.use_segment_length(*segment_length);
+            let key = generator.generate(12345);
+            let verifier = create_verifier();
+
+            // When
+            let result = verifier.verify(&key);
+
+            // Then
+            assert_eq!(Status::Valid, result);
+            assert_eq!(*segment_length, key.layout().segment_length);
+            assert_eq!(8 + 4 * segment_length.bytes() + 2, key.get_bytes().len());
+        }
+    }
+
+    #[test]
+    pub fn first_byte_of_wide_segment_should_match_single_byte_segment() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_segment_length(SegmentLength::Two);
+
+        // When
+        let key = generator.generate(12345);
+
+        // Then
+        let narrow = generate_key(12345).get_bytes();
+        assert_eq!(narrow[8], key.get_bytes()[8]);
+        assert_ne!(key.get_bytes()[8], key.get_bytes()[9]);
+    }
+
+    #[test]
+    pub fn key_with_altered_second_segment_byte_should_be_forged() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_segment_length(SegmentLength::Two);
+        let key = generator.generate(12345);
+        let mut bytes = key.get_bytes();
+        bytes[9] ^= 0x01;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify(&LicenseKey::new(bytes, key.layout()));
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn key_with_wide_segments_should_parse_with_layout() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_segment_length(SegmentLength::Four);
+        let key = generator.generate(12345);
+        let layout = KeyLayout {
+            segment_length: SegmentLength::Four,
+            ..KeyLayout::default()
+        };
+        let verifier = create_verifier();
+
+        // When
+        let serialized = key.serialize(&HexFormat);
+        let parsed = LicenseKey::parse_with_layout(&serialized, &HexFormat, layout).unwrap();
+        let result = verifier.verify(&parsed);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+        assert_eq!(Some(&key.get_bytes()[12..16]), parsed.get_segment(1));
+    }
+
+    #[test]
+    pub fn hasher_with_wide_output_should_fill_segment_in_one_call() {
+        // Given
+        struct WideHasher {}
+        impl KeyHasher for WideHasher {
+            fn hash(&self, _: u64, _: u64, _: u64, _: u64) -> u8 {
+                unreachable!("the segment is filled in one call")
+            }
+
+            fn hash_segment(&self, seed: u64, a: u64, b: u64, c: u64, segment: &mut [u8]) {
+                let value = (seed ^ a ^ b ^ c) as u32;
+                segment.copy_from_slice(&value.to_be_bytes()[..segment.len()]);
+            }
+        }
+        let mut generator = Generator::new(WideHasher {}, vec![(114, 83, 170)]);
+        generator.use_segment_length(SegmentLength::Four);
+        let verifier = Verifier::new(WideHasher {}, vec![ByteCheck::new(0, (114, 83, 170))]);
+
+        // When
+        let key = generator.generate(0x0102_0304_0506_0708);
+        let result = verifier.verify(&key);
+
+        // Then
+        let expected = ((0x0102_0304_0506_0708_u64 ^ 114 ^ 83 ^ 170) as u32).to_be_bytes();
+        assert_eq!(Some(&expected[..]), key.get_segment(0));
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn key_with_features_should_be_valid() {
+        // Given
+        let key = create_generator().generate_with_features(12345, 0b101);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+        assert_eq!(0b101, key.features());
+        assert_eq!(0, generate_key(12345).features());
+    }
+
+    #[test]
+    pub fn key_with_flipped_feature_bit_should_be_forged() {
+        // Given
+        let key = create_generator().generate_with_features(12345, 0b101);
+        let mut bytes = key.get_bytes();
+        bytes[11] ^= 0b010;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let key = LicenseKey::new(bytes, key.layout());
+
+        // When
+        let result = create_verifier().verify(&key);
+
+        // Then
+        assert_eq!(0b111, key.features());
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn machine_key_should_only_be_valid_on_its_machine() {
+        // Given
+        let key = create_generator().generate_for_machine(12345, 0xC0FF_EE00_1234_5678);
+        let mut right = create_verifier();
+        right.use_machine_id(0xC0FF_EE00_1234_5678);
+        let mut wrong = create_verifier();
+        wrong.use_machine_id(0xC0FF_EE00_1234_5679);
+
+        // When
+        let on_right_machine = right.verify(&key);
+        let on_wrong_machine = wrong.verify(&key);
+        let on_unknown_machine = create_verifier().verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, on_right_machine);
+        assert_eq!(Status::WrongMachine, on_wrong_machine);
+        assert_eq!(Status::WrongMachine, on_unknown_machine);
+        assert_eq!(generate_key(12345).get_bytes().len(), key.get_bytes().len());
+    }
+
+    #[test]
+    pub fn key_without_machine_should_be_valid_on_every_machine() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.use_machine_id(42);
+
+        // When
+        let result = verifier.verify(&key);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn status_exit_codes_should_be_stable() {
+        // Given
+        let statuses = [
+            Status::Valid,
+            Status::Invalid,
+            Status::Blocked,
+            Status::Forged,
+            Status::RateLimited,
+            Status::Expired,
+            Status::WrongRegion,
+            Status::UnsupportedVersion,
+            Status::WrongMachine,
+        ];
+
+        // When
+        let codes: Vec<i32> = statuses.iter().map(|status| status.exit_code()).collect();
+
+        // Then
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7, 8], codes);
+    }
+
+    #[test]
+    pub fn status_should_have_message() {
+        // Given, When
+        let valid = Status::Valid.to_string();
+        let expired = Status::Expired.to_string();
+
+        // Then
+        assert_eq!("the license key is valid", valid);
+        assert_eq!("the license key has expired", expired);
+    }
+
+    #[test]
+    pub fn adding_check_with_wrong_iv_should_make_key_forged() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        let before = verifier.verify(&key);
+
+        // When
+        verifier.add_check(ByteCheck::new(1, (1, 2, 3)));
+
+        // Then
+        assert_eq!(Status::Valid, before);
+        assert_eq!(Status::Forged, verifier.verify(&key));
+        assert_eq!(3, verifier.checks().len());
+    }
+
+    #[test]
+    pub fn removing_check_should_only_remove_checks_for_ordinal() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.add_check(ByteCheck::new(1, (1, 2, 3)));
+
+        // When
+        let removed = verifier.remove_check(1);
+        let removed_again = verifier.remove_check(1);
+
+        // Then
+        assert!(removed);
+        assert!(!removed_again);
+        let ordinals: Vec<u8> = verifier
+            .checks()
+            .iter()
+            .map(|check| check.ordinal)
+            .collect();
+        assert_eq!(vec![0, 2], ordinals);
+        assert_eq!(Status::Valid, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn identical_keys_should_be_deduplicated_in_set() {
+        // Given
+        let first = generate_key(12345);
+        let second = generate_key(12345);
+        let other = generate_key(67890);
+
+        // When
+        let keys: std::collections::HashSet<_> =
+            vec![first.clone(), second, other].into_iter().collect();
+
+        // Then
+        assert_eq!(2, keys.len());
+        assert!(keys.contains(&first));
+        assert_ne!(first, generate_key(67890));
+    }
+
+    fn layout_from_bits(bits: u16) -> KeyLayout {
+        let flag = |bit: u16| bits & (1 << bit) != 0;
+        KeyLayout {
+            seed_length: match (bits & 0b11) % 3 {
+                0 => SeedLength::Four,
+                1 => SeedLength::Eight,
+                _ => SeedLength::Sixteen,
+            },
+            seed_checksum: flag(2),
+            version: flag(3),
+            flags: flag(14),
+            device_index: flag(4),
+            region: flag(5),
+            expiry: flag(6),
+            issue_date: flag(7),
+            features: flag(8),
+            machine: flag(9),
+            claims: flag(10),
+            long_checksum: flag(11),
+            segment_length: match ((bits >> 12) & 0b11) % 3 {
+                0 => SegmentLength::One,
+                1 => SegmentLength::Two,
+                _ => SegmentLength::Four,
+            },
+        }
+    }
+
+    fn inspect_key(key: &LicenseKey) {
+        let verifier = create_verifier();
+        verifier.verify(key);
+        verifier.verify_at(key, 1_800_000_000);
+        verifier.verify_outcome(key);
+        let mut limiter = RateLimiter::new(MemoryAttemptStore::default(), 3, 60);
+        limiter.verify(&verifier, key, 0);
+        let mut stream = verifier.stream_with_layout(key.layout());
+        stream.feed(key.as_bytes());
+        stream.finish();
+        key.seed();
+        key.wide_seed();
+        key.payload();
+        key.verify_checksum();
+        key.seed_checksum_ok();
+        key.version();
+        key.device_index();
+        key.region();
+        key.expires_at();
+        key.issued_at();
+        key.features();
+        key.claims();
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_strings_should_not_panic(input in "\\PC*", bits in 0_u16..) {
+            let layout = layout_from_bits(bits);
+            let formats: Vec<Box<dyn Serializer>> = vec![
+                Box::new(HexFormat),
+                Box::new(Base32Format),
+                Box::new(Rfc4648Base32Format::new(true)),
+                Box::new(Base58Format),
+                Box::new(Base64UrlFormat),
+                Box::new(Grouped::new(HexFormat, 4, '-')),
+            ];
+            for format in formats.iter() {
+                if let Ok(key) = LicenseKey::parse_with_layout(&input, format.as_ref(), layout) {
+                    inspect_key(&key);
+                }
+            }
+        }
+
+        #[test]
+        fn arbitrary_bytes_should_not_panic(
+            bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..64),
+            bits in 0_u16..,
+        ) {
+            let layout = layout_from_bits(bits);
+            if let Ok(key) = LicenseKey::from_bytes_with_layout(&bytes, layout) {
+                inspect_key(&key);
+            }
+            inspect_key(&LicenseKey::new(bytes, layout));
+        }
+    }
+
+    #[test]
+    pub fn fingerprint_should_be_deterministic() {
+        // Given
+        let key = generate_key(12345);
+
+        // When
+        let fingerprint = key.fingerprint();
+
+        // Then
+        assert_eq!(8, fingerprint.len());
+        assert_eq!(fingerprint, generate_key(12345).fingerprint());
+        assert_ne!(fingerprint, generate_key(67890).fingerprint());
+        assert!(!key.serialize(&HexFormat).contains(&fingerprint));
+    }
+
+    #[test]
+    pub fn keys_from_same_seed_should_share_seed() {
+        // Given
+        let generator = create_generator();
+        let first = generator.generate_device_key(12345, 1);
+        let second = generator.generate_with_expiry(12345, 1_800_000_000);
+
+        // When
+        let result = first.same_seed(&second);
+
+        // Then
+        assert!(result);
+        assert_ne!(first.serialize(&HexFormat), second.serialize(&HexFormat));
+    }
+
+    #[test]
+    pub fn keys_from_different_seeds_should_not_share_seed() {
+        // Given
+        let generator = create_generator();
+        let short = LicenseKey::new(vec![0x00, 0x00], KeyLayout::default());
+
+        // When
+        let different = generate_key(12345).same_seed(&generate_key(67890));
+        let narrow = generator
+            .generate_seed(12345_u32)
+            .same_seed(&generate_key(12345));
+        let truncated = short.same_seed(&short);
+
+        // Then
+        assert!(!different);
+        assert!(!narrow);
+        assert!(!truncated);
     }
 }
@@ -0,0 +1,131 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// Represents a source of random numbers.
+///
+/// The verifier gets random numbers from a random source when it
+/// selects which byte checks to run, which makes it possible to control
+/// the selection in tests. See [`Verifier::use_check_subset`].
+///
+/// [`Verifier::use_check_subset`]: struct.Verifier.html#method.use_check_subset
+pub trait RandomSource: Send + Sync {
+    /// Gets the next random number.
+    fn next_u32(&self) -> u32;
+}
+
+impl core::fmt::Debug for dyn RandomSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("RandomSource")
+    }
+}
+
+/// A [`RandomSource`] that produces a deterministic sequence of
+/// numbers from a seed, using a xorshift generator.
+///
+/// The numbers are not cryptographically secure. Seed the random source
+/// with something that differs between runs, such as the current time,
+/// to get a different sequence every time.
+///
+/// [`RandomSource`]: trait.RandomSource.html
+#[derive(Debug)]
+pub struct SeededRandom {
+    state: AtomicU32,
+}
+
+impl SeededRandom {
+    /// Creates a new random source with the specified seed.
+    pub fn new(seed: u32) -> Self {
+        // A xorshift generator never leaves the zero state
+        let seed = if seed == 0 { 0x9E37_79B9 } else { seed };
+        Self {
+            state: AtomicU32::new(seed),
+        }
+    }
+}
+
+impl RandomSource for SeededRandom {
+    fn next_u32(&self) -> u32 {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            let mut next = state;
+            next ^= next << 13;
+            next ^= next >> 17;
+            next ^= next << 5;
+            match self.state.compare_exchange_weak(
+                state,
+                next,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return next,
+                Err(current) => state = current,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_verifier, generate_key};
+    use crate::{ByteCheck, Status};
+
+    struct FixedRandom(u32);
+    impl RandomSource for FixedRandom {
+        fn next_u32(&self) -> u32 {
+            self.0
+        }
+    }
+
+    #[test]
+    pub fn seeded_random_should_be_deterministic() {
+        // Given
+        let first = SeededRandom::new(12345);
+        let second = SeededRandom::new(12345);
+
+        // When
+        let first: Vec<u32> = (0..5).map(|_| first.next_u32()).collect();
+        let second: Vec<u32> = (0..5).map(|_| second.next_u32()).collect();
+
+        // Then
+        assert_eq!(first, second);
+        assert_ne!(first[0], first[1]);
+    }
+
+    #[test]
+    pub fn verifier_should_only_run_selected_checks() {
+        // Given
+        let key = generate_key(12345);
+        let mut skipping = create_verifier();
+        skipping.add_check(ByteCheck::new(1, (1, 2, 3)));
+        skipping.use_check_subset(2, FixedRandom(0));
+        let mut selecting = create_verifier();
+        selecting.add_check(ByteCheck::new(1, (1, 2, 3)));
+        selecting.use_check_subset(2, FixedRandom(2));
+
+        // When
+        let without_wrong_check = skipping.verify(&key);
+        let with_wrong_check = selecting.verify(&key);
+
+        // Then
+        assert_eq!(3, skipping.pool_size());
+        assert_eq!(2, skipping.subset_count());
+        assert_eq!(Status::Valid, without_wrong_check);
+        assert_eq!(Status::Forged, with_wrong_check);
+    }
+
+    #[test]
+    pub fn verifier_should_select_different_checks_per_verification() {
+        // Given
+        let key = generate_key(12345);
+        let mut verifier = create_verifier();
+        verifier.add_check(ByteCheck::new(1, (1, 2, 3)));
+        verifier.use_check_subset(1, SeededRandom::new(12345));
+
+        // When
+        let statuses: Vec<Status> = (0..30).map(|_| verifier.verify(&key)).collect();
+
+        // Then
+        assert!(statuses.contains(&Status::Valid));
+        assert!(statuses.contains(&Status::Forged));
+    }
+}
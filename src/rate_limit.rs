@@ -0,0 +1,262 @@
+use alloc::vec::Vec;
+
+use crate::{BlockList, KeyHasher, LicenseKey, Map, Status, Verifier};
+
+/// Represents a store that keeps track of failed
+/// verification attempts per seed.
+///
+/// Implement this trait to keep the attempts somewhere
+/// else than in memory, such as in a database or a cache.
+pub trait AttemptStore {
+    /// Gets the number of failed attempts for the seed
+    /// that happened at or after the specified time.
+    fn failures(&self, seed: u64, since: u64) -> usize;
+
+    /// Records a failed attempt for the seed at the specified time.
+    ///
+    /// Failed attempts that happened before `since` are outside of the
+    /// window of the rate limiter and should be dropped, so that the store
+    /// doesn't grow without bound.
+    fn record_failure(&mut self, seed: u64, time: u64, since: u64);
+
+    /// Removes all failed attempts for the seed.
+    fn reset(&mut self, seed: u64);
+}
+
+/// The number of seeds that a [`MemoryAttemptStore`] keeps track of by default.
+///
+/// [`MemoryAttemptStore`]: struct.MemoryAttemptStore.html
+pub const DEFAULT_TRACKED_SEEDS: usize = 10_000;
+
+/// An in-memory [`AttemptStore`].
+///
+/// Failed attempts outside of the window are dropped when a failure is
+/// recorded, and at most a fixed number of seeds are tracked. When a new
+/// seed would exceed the limit, the seed with the oldest latest failure
+/// is forgotten, which keeps the memory bounded no matter how many
+/// different seeds are sent.
+///
+/// [`AttemptStore`]: trait.AttemptStore.html
+#[derive(Debug)]
+pub struct MemoryAttemptStore {
+    attempts: Map<u64, Vec<u64>>,
+    capacity: usize,
+}
+
+impl MemoryAttemptStore {
+    /// Creates a new in-memory store that keeps track of
+    /// failed attempts for at most `capacity` seeds.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            attempts: Map::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Gets the number of seeds with failed attempts.
+    pub fn len(&self) -> usize {
+        self.attempts.len()
+    }
+
+    /// Checks whether or not there are no failed attempts.
+    pub fn is_empty(&self) -> bool {
+        self.attempts.is_empty()
+    }
+
+    fn make_room(&mut self, since: u64) {
+        // Forget the seeds without any failures within the window first
+        self.attempts
+            .retain(|_, times| times.iter().any(|time| *time >= since));
+        if self.attempts.len() < self.capacity {
+            return;
+        }
+        let oldest = self
+            .attempts
+            .iter()
+            .min_by_key(|(_, times)| times.iter().max().copied())
+            .map(|(seed, _)| *seed);
+        if let Some(seed) = oldest {
+            self.attempts.remove(&seed);
+        }
+    }
+}
+
+impl Default for MemoryAttemptStore {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_TRACKED_SEEDS)
+    }
+}
+
+impl AttemptStore for MemoryAttemptStore {
+    fn failures(&self, seed: u64, since: u64) -> usize {
+        match self.attempts.get(&seed) {
+            Some(times) => times.iter().filter(|time| **time >= since).count(),
+            None => 0,
+        }
+    }
+
+    fn record_failure(&mut self, seed: u64, time: u64, since: u64) {
+        if !self.attempts.contains_key(&seed) && self.attempts.len() >= self.capacity {
+            self.make_room(since);
+        }
+        let times = self.attempts.entry(seed).or_default();
+        times.retain(|time| *time >= since);
+        times.push(time);
+    }
+
+    fn reset(&mut self, seed: u64) {
+        self.attempts.remove(&seed);
+    }
+}
+
+/// Limits the number of failed verification attempts per seed.
+///
+/// When a seed has failed verification too many times within the
+/// configured window, any further attempts will return
+/// [`Status::RateLimited`] until the window has passed.
+/// A successful verification resets the failed attempts for the seed.
+///
+/// [`Status::RateLimited`]: enum.Status.html#variant.RateLimited
+#[derive(Debug)]
+pub struct RateLimiter<S: AttemptStore = MemoryAttemptStore> {
+    store: S,
+    threshold: usize,
+    window: u64,
+}
+
+impl<S: AttemptStore> RateLimiter<S> {
+    /// Creates a new rate limiter that allows `threshold` failed
+    /// attempts per seed within `window` time units.
+    ///
+    /// The time unit is decided by the caller, but
+    /// must be the same that is passed to [`RateLimiter::verify`].
+    ///
+    /// [`RateLimiter::verify`]: struct.RateLimiter.html#method.verify
+    pub fn new(store: S, threshold: usize, window: u64) -> Self {
+        Self {
+            store,
+            threshold,
+            window,
+        }
+    }
+
+    /// Perform verification on the provided license key with
+    /// the provided verifier, unless the seed has been rate limited.
+    pub fn verify<T: KeyHasher, B: BlockList>(
+        &mut self,
+        verifier: &Verifier<T, B>,
+        key: &LicenseKey,
+        now: u64,
+    ) -> Status {
+        let seed = key.get_seed(&verifier.hasher);
+        let since = now.saturating_sub(self.window);
+        if self.store.failures(seed, since) >= self.threshold {
+            return Status::RateLimited;
+        }
+
+        let status = verifier.verify(key);
+        match status {
+            Status::Valid | Status::Trial { .. } => self.store.reset(seed),
+            Status::Invalid | Status::Forged => self.store.record_failure(seed, now, since),
+            _ => {}
+        }
+
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_verifier, generate_key, TestHasher};
+    use crate::Generator;
+
+    fn forge_key(seed: u64) -> LicenseKey {
+        let generator =
+            Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        generator.generate(seed)
+    }
+
+    #[test]
+    pub fn exceeding_failure_threshold_should_return_rate_limited() {
+        // Given
+        let verifier = create_verifier();
+        let mut limiter = RateLimiter::new(MemoryAttemptStore::default(), 2, 60);
+        limiter.verify(&verifier, &forge_key(12345), 100);
+        limiter.verify(&verifier, &forge_key(12345), 101);
+
+        // When
+        let result = limiter.verify(&verifier, &generate_key(12345), 102);
+
+        // Then
+        assert_eq!(Status::RateLimited, result);
+    }
+
+    #[test]
+    pub fn rate_limit_should_expire_after_window() {
+        // Given
+        let verifier = create_verifier();
+        let mut limiter = RateLimiter::new(MemoryAttemptStore::default(), 2, 60);
+        limiter.verify(&verifier, &forge_key(12345), 100);
+        limiter.verify(&verifier, &forge_key(12345), 101);
+
+        // When
+        let result = limiter.verify(&verifier, &generate_key(12345), 200);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn successful_verification_should_reset_failures() {
+        // Given
+        let verifier = create_verifier();
+        let mut limiter = RateLimiter::new(MemoryAttemptStore::default(), 2, 60);
+        limiter.verify(&verifier, &forge_key(12345), 100);
+        limiter.verify(&verifier, &generate_key(12345), 101);
+        limiter.verify(&verifier, &forge_key(12345), 102);
+
+        // When
+        let result = limiter.verify(&verifier, &generate_key(12345), 103);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn failures_outside_of_window_should_be_dropped() {
+        // Given
+        let mut store = MemoryAttemptStore::default();
+        store.record_failure(12345, 100, 40);
+        store.record_failure(12345, 101, 41);
+
+        // When
+        store.record_failure(12345, 200, 140);
+
+        // Then
+        assert_eq!(1, store.failures(12345, 0));
+        assert_eq!(1, store.len());
+    }
+
+    #[test]
+    pub fn store_should_not_track_more_seeds_than_capacity() {
+        // Given
+        let mut store = MemoryAttemptStore::with_capacity(2);
+        store.record_failure(1, 100, 40);
+        store.record_failure(2, 110, 50);
+
+        // When
+        store.record_failure(3, 120, 60);
+        let tracked = [
+            store.failures(1, 0),
+            store.failures(2, 0),
+            store.failures(3, 0),
+        ];
+        store.record_failure(4, 300, 240);
+
+        // Then
+        assert_eq!([0, 1, 1], tracked);
+        assert_eq!(1, store.failures(4, 0));
+        assert_eq!(1, store.len());
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::vec;
+
+use crate::{ByteCheck, Generator, HexFormat, KeyHasher, LicenseKey, Status, Verifier};
+
+const REFERENCE_VECTORS: &str = include_str!("reference_vectors.txt");
+
+const REFERENCE_IV: &[(u64, u64, u64)] =
+    &[(114, 83, 170), (60, 208, 27), (69, 14, 202), (61, 232, 54)];
+const SHORT_IV: &[(u64, u64, u64)] = &[(1, 2, 3)];
+
+/// The hasher that the reference vectors are produced with.
+///
+/// The hashed byte is the lowest byte of the seed and the initialization
+/// vector values XORed together. It's trivial to reproduce in another
+/// language, but **don't use it** for real license keys.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReferenceHasher;
+impl KeyHasher for ReferenceHasher {
+    fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+        ((seed ^ a ^ b ^ c) & 0xFF) as u8
+    }
+}
+
+/// A known-answer vector for the generator, which pins the format of the
+/// license keys. See [`GENERATOR_VECTORS`].
+///
+/// [`GENERATOR_VECTORS`]: constant.GENERATOR_VECTORS.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneratorVector {
+    /// The seed of the license key.
+    pub seed: u64,
+    /// The initialization vector of the generator.
+    pub iv: &'static [(u64, u64, u64)],
+    /// The expected license key as a lowercase hex string.
+    pub key: &'static str,
+    /// The expected checksum, which is the last two bytes of the license key.
+    pub checksum: u16,
+}
+
+/// Known-answer vectors for a [`Generator`] with the default settings
+/// and a [`ReferenceHasher`]. These vectors must never change.
+///
+/// ```
+/// use license_key::*;
+/// for vector in GENERATOR_VECTORS.iter() {
+///     let generator = Generator::new(ReferenceHasher, vector.iv.to_vec());
+///     let key = generator.generate(vector.seed);
+///     assert_eq!(vector.key, key.serialize(&HexFormat));
+/// }
+/// ```
+///
+/// [`Generator`]: struct.Generator.html
+/// [`ReferenceHasher`]: struct.ReferenceHasher.html
+pub const GENERATOR_VECTORS: &[GeneratorVector] = &[
+    vector(0, REFERENCE_IV, "00000000000000008bf781e38f98", 0x8f98),
+    vector(42, REFERENCE_IV, "000000000000002aa1ddabc9a6ce", 0xa6ce),
+    vector(
+        1234567891011121314,
+        REFERENCE_IV,
+        "112210f4b2d230a229552341e723",
+        0xe723,
+    ),
+    vector(
+        u64::MAX,
+        REFERENCE_IV,
+        "ffffffffffffffff74087e1c95c6",
+        0x95c6,
+    ),
+    vector(0, SHORT_IV, "00000000000000000083af", 0x83af),
+    vector(42, SHORT_IV, "000000000000002a2a0204", 0x0204),
+    vector(
+        1234567891011121314,
+        SHORT_IV,
+        "112210f4b2d230a2a29de2",
+        0x9de2,
+    ),
+    vector(u64::MAX, SHORT_IV, "ffffffffffffffffff83af", 0x83af),
+];
+
+const fn vector(
+    seed: u64,
+    iv: &'static [(u64, u64, u64)],
+    key: &'static str,
+    checksum: u16,
+) -> GeneratorVector {
+    GeneratorVector {
+        seed,
+        iv,
+        key,
+        checksum,
+    }
+}
+
+/// Runs the reference vectors that ship with the crate and returns
+/// whether or not every license key got its expected status.
+///
+/// Each reference vector consists of a verifier configuration, a
+/// license key and the status that the license key is expected to get.
+/// Call this from a test to make sure that the observable verification
+/// behavior hasn't changed with a new version of the crate.
+pub fn run_reference_vectors() -> bool {
+    reference_vectors().all(
+        |(config, key, expected)| match create_reference_verifier(config) {
+            Some(verifier) => verifier.verify(&key) == expected,
+            None => false,
+        },
+    )
+}
+
+/// Runs the generator vectors in [`GENERATOR_VECTORS`] and returns
+/// whether or not every license key and checksum was generated as expected.
+///
+/// [`GENERATOR_VECTORS`]: constant.GENERATOR_VECTORS.html
+pub fn run_generator_vectors() -> bool {
+    GENERATOR_VECTORS.iter().all(|vector| {
+        let generator = Generator::new(ReferenceHasher, vector.iv.to_vec());
+        let key = generator.generate(vector.seed);
+        key.serialize(&HexFormat) == vector.key
+            && key.get_checksum() == vector.checksum.to_be_bytes()
+    })
+}
+
+fn reference_vectors() -> impl Iterator<Item = (&'static str, LicenseKey, Status)> {
+    REFERENCE_VECTORS
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let config = parts.next().unwrap();
+            let key = LicenseKey::parse(parts.next().unwrap(), &HexFormat).unwrap();
+            let status = parse_status(parts.next().unwrap());
+            (config, key, status)
+        })
+}
+
+fn parse_status(input: &str) -> Status {
+    match input {
+        "valid" => Status::Valid,
+        "invalid" => Status::Invalid,
+        "blocked" => Status::Blocked,
+        "forged" => Status::Forged,
+        _ => panic!("unknown reference status '{}'", input),
+    }
+}
+
+fn create_reference_verifier(config: &str) -> Option<Verifier<ReferenceHasher>> {
+    let mut checks = vec![
+        ByteCheck::new(0, (114, 83, 170)),
+        ByteCheck::new(2, (69, 14, 202)),
+    ];
+    if config == "tolerant" {
+        checks.push(ByteCheck::new(3, (61, 232, 54)));
+    }
+
+    let mut verifier = Verifier::new(ReferenceHasher, checks);
+    match config {
+        "basic" => {}
+        "tolerant" => verifier.tolerate_single_mismatch(true),
+        "blocked" => verifier.block(1234567891011121314),
+        "payload_length" => verifier.expect_payload_length(4),
+        "folded" => verifier.fold_length_into_checksum(true),
+        _ => return None,
+    }
+
+    Some(verifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn all_reference_vectors_should_have_expected_status() {
+        for (config, key, expected) in reference_vectors() {
+            // Given
+            let verifier = create_reference_verifier(config).unwrap();
+
+            // When
+            let result = verifier.verify(&key);
+
+            // Then
+            assert_eq!(expected, result, "{} {}", config, key.serialize(&HexFormat));
+        }
+
+        assert!(run_reference_vectors());
+    }
+
+    #[test]
+    pub fn all_generator_vectors_should_produce_expected_keys() {
+        for vector in GENERATOR_VECTORS.iter() {
+            // Given
+            let generator = Generator::new(ReferenceHasher, vector.iv.to_vec());
+
+            // When
+            let key = generator.generate(vector.seed);
+
+            // Then
+            assert_eq!(vector.key, key.serialize(&HexFormat));
+            assert_eq!(vector.checksum.to_be_bytes(), key.get_checksum());
+        }
+
+        assert!(run_generator_vectors());
+    }
+}
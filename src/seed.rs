@@ -0,0 +1,227 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+
+use crate::KeyHasher;
+
+/// Represents an integer type that can be used as the seed of a license key.
+///
+/// The seed is stored in the license key with the byte length of the type.
+/// Seeds that aren't 64 bits long are folded into a 64-bit seed by the
+/// hasher together with their length before the payload is calculated
+/// (see [`KeyHasher::fold_seed`]), so the same number as seeds of different
+/// types gives different license keys. Blocked seeds are only matched
+/// against seeds of the same type (see [`Verifier::block_seed`]).
+/// See [`Generator::generate_seed`] and [`LicenseKey::seed_as`].
+///
+/// [`KeyHasher::fold_seed`]: trait.KeyHasher.html#method.fold_seed
+/// [`Verifier::block_seed`]: struct.Verifier.html#method.block_seed
+/// [`Generator::generate_seed`]: struct.Generator.html#method.generate_seed
+/// [`LicenseKey::seed_as`]: struct.LicenseKey.html#method.seed_as
+pub trait Seed: Copy {
+    /// The length of the seed in a license key.
+    const LENGTH: SeedLength;
+
+    /// Gets the big-endian bytes of the seed.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Creates a seed from big-endian bytes of the seed length,
+    /// or returns `None` if there are too few or too many bytes.
+    fn from_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+/// The length of the seed of a license key. See [`Seed`].
+///
+/// [`Seed`]: trait.Seed.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeedLength {
+    /// A 32-bit seed.
+    Four,
+    /// A 64-bit seed. This is the default.
+    #[default]
+    Eight,
+    /// A 128-bit seed.
+    Sixteen,
+}
+
+impl SeedLength {
+    /// Gets the number of bytes in a seed.
+    pub fn bytes(&self) -> usize {
+        match self {
+            SeedLength::Four => 4,
+            SeedLength::Eight => 8,
+            SeedLength::Sixteen => 16,
+        }
+    }
+
+    pub(crate) fn hash_seed<T: KeyHasher + ?Sized>(&self, hasher: &T, bytes: &[u8]) -> u64 {
+        // The length of the seed is always part of what gets folded,
+        // so seeds of different lengths don't share a 64-bit seed.
+        match (self, u64::from_bytes(bytes)) {
+            (SeedLength::Eight, Some(seed)) => seed,
+            _ => hasher.fold_seed(bytes),
+        }
+    }
+}
+
+impl Seed for u32 {
+    const LENGTH: SeedLength = SeedLength::Four;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(u32::from_be_bytes)
+    }
+}
+
+impl Seed for u64 {
+    const LENGTH: SeedLength = SeedLength::Eight;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(u64::from_be_bytes)
+    }
+}
+
+impl Seed for u128 {
+    const LENGTH: SeedLength = SeedLength::Sixteen;
+
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_be_bytes().to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(u128::from_be_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier};
+    use crate::{HexFormat, KeyLayout, LicenseKey, Status, UnblockResult};
+
+    fn round_trip<S: Seed + PartialEq + core::fmt::Debug>(seed: S) {
+        // Given
+        let generator = create_generator();
+        let verifier = create_verifier();
+        let layout = KeyLayout {
+            seed_length: S::LENGTH,
+            ..KeyLayout::default()
+        };
+
+        // When
+        let key = generator.generate_seed(seed);
+        let parsed =
+            LicenseKey::parse_with_layout(&key.serialize(&HexFormat), &HexFormat, layout).unwrap();
+
+        // Then
+        assert_eq!(S::LENGTH.bytes() + 6, key.as_bytes().len());
+        assert_eq!(Some(seed), parsed.seed_as::<S>());
+        assert!(parsed.same_seed(&key));
+        assert_eq!(Status::Valid, verifier.verify(&parsed));
+    }
+
+    #[test]
+    pub fn seeds_of_every_width_should_round_trip() {
+        round_trip(0xDEAD_BEEF_u32);
+        round_trip(1234567891011121314_u64);
+        round_trip((1_u128 << 100) | 12345);
+    }
+
+    #[test]
+    pub fn u64_seed_should_generate_same_key_as_generate() {
+        // Given
+        let generator = create_generator();
+
+        // When
+        let result = generator.generate_seed(12345_u64);
+
+        // Then
+        assert_eq!(generator.generate(12345), result);
+    }
+
+    #[test]
+    pub fn seed_of_other_width_should_not_be_returned() {
+        // Given
+        let key = create_generator().generate_seed(12345_u32);
+
+        // When
+        let result = key.seed_as::<u64>();
+
+        // Then
+        assert_eq!(None, result);
+        assert_eq!(Some(12345_u32), key.seed_as::<u32>());
+    }
+
+    #[test]
+    pub fn seeds_of_different_widths_should_have_different_payloads() {
+        // Given
+        let generator = create_generator();
+
+        // When
+        let narrow = generator.generate_seed(5_u32);
+        let regular = generator.generate_seed(5_u64);
+        let wide = generator.generate_seed(5_u128);
+
+        // Then
+        assert_ne!(narrow.payload(), regular.payload());
+        assert_ne!(narrow.payload(), wide.payload());
+        assert_ne!(regular.payload(), wide.payload());
+    }
+
+    #[test]
+    pub fn blocking_narrow_seed_should_only_block_narrow_key() {
+        // Given
+        let narrow = create_generator().generate_seed(12345_u32);
+        let regular = create_generator().generate_seed(12345_u64);
+        let mut verifier = create_verifier();
+
+        // When
+        verifier.block(12345);
+        let unblocked = verifier.verify(&narrow);
+        verifier.unblock(12345);
+        verifier.block_seed(12345_u32);
+
+        // Then
+        assert_eq!(Status::Valid, unblocked);
+        assert_eq!(Status::Blocked, verifier.verify(&narrow));
+        assert_eq!(Status::Valid, verifier.verify(&regular));
+        assert_eq!(UnblockResult::Unblocked, verifier.unblock_seed(12345_u32));
+        assert_eq!(Status::Valid, verifier.verify(&narrow));
+    }
+
+    #[test]
+    pub fn blocking_seed_of_every_width_should_block_key() {
+        // Given
+        let generator = create_generator();
+        let keys = [
+            generator.generate_seed(12345_u32),
+            generator.generate_seed(12345_u64),
+            generator.generate_seed(12345_u128),
+        ];
+        let mut verifier = create_verifier();
+
+        // When
+        verifier.block_seed(12345_u32);
+        verifier.block_seed(12345_u64);
+        verifier.block_seed(12345_u128);
+
+        // Then
+        for key in keys.iter() {
+            assert_eq!(Status::Blocked, verifier.verify(key));
+        }
+    }
+
+    #[test]
+    pub fn seed_from_bytes_of_wrong_length_should_be_none() {
+        assert_eq!(Some(0x0102_0304), u32::from_bytes(&[1, 2, 3, 4]));
+        assert_eq!(None, u32::from_bytes(&[1, 2, 3]));
+        assert_eq!(None, u64::from_bytes(&[1, 2, 3, 4]));
+        assert_eq!(None, u128::from_bytes(&[0; 17]));
+    }
+}
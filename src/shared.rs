@@ -0,0 +1,151 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{
+    BlockList, KeyHasher, LicenseError, LicenseKey, MemoryBlockList, Status, UnblockResult,
+    Verifier,
+};
+
+/// A cloneable handle to a [`Verifier`] that can be shared between threads.
+///
+/// License keys are verified under a read lock, which means that any
+/// number of threads can verify license keys at the same time. Seeds can
+/// be blocked and unblocked through any handle, which takes a write lock.
+///
+/// A [`Verifier`] is `Send` and `Sync` whenever its hasher and blocklist
+/// are, so use a plain `Arc<Verifier<T>>` if it never changes.
+///
+/// [`Verifier`]: struct.Verifier.html
+#[derive(Debug)]
+pub struct SharedVerifier<T: KeyHasher, B: BlockList = MemoryBlockList> {
+    verifier: Arc<RwLock<Verifier<T, B>>>,
+}
+
+impl<T: KeyHasher, B: BlockList> Clone for SharedVerifier<T, B> {
+    fn clone(&self) -> Self {
+        Self {
+            verifier: Arc::clone(&self.verifier),
+        }
+    }
+}
+
+impl<T: KeyHasher, B: BlockList> SharedVerifier<T, B> {
+    /// Creates a new shared verifier from the specified verifier.
+    pub fn new(verifier: Verifier<T, B>) -> Self {
+        Self {
+            verifier: Arc::new(RwLock::new(verifier)),
+        }
+    }
+
+    /// Perform verification on the provided license key.
+    /// See [`Verifier::verify`].
+    ///
+    /// [`Verifier::verify`]: struct.Verifier.html#method.verify
+    pub fn verify(&self, key: &LicenseKey) -> Status {
+        self.read().verify(key)
+    }
+
+    /// Perform verification on the provided license key and return
+    /// an error if it isn't valid. See [`Verifier::check`].
+    ///
+    /// [`Verifier::check`]: struct.Verifier.html#method.check
+    pub fn check(&self, key: &LicenseKey) -> Result<(), LicenseError> {
+        self.read().check(key)
+    }
+
+    /// Checks whether or not the specified seed has been blocked.
+    /// See [`Verifier::is_blocked`].
+    ///
+    /// [`Verifier::is_blocked`]: struct.Verifier.html#method.is_blocked
+    pub fn is_blocked(&self, seed: u64) -> bool {
+        self.read().is_blocked(seed)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Verifier<T, B>> {
+        // A panic while holding the lock can't leave the verifier half-updated
+        self.verifier
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Verifier<T, B>> {
+        self.verifier
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T: KeyHasher> SharedVerifier<T> {
+    /// Blocks the specified seed from being used. See [`Verifier::block`].
+    ///
+    /// [`Verifier::block`]: struct.Verifier.html#method.block
+    pub fn block(&self, seed: u64) {
+        self.write().block(seed);
+    }
+
+    /// Unblocks the specified seed and returns whether or not
+    /// the seed was blocked in the first place. See [`Verifier::unblock`].
+    ///
+    /// [`Verifier::unblock`]: struct.Verifier.html#method.unblock
+    pub fn unblock(&self, seed: u64) -> UnblockResult {
+        self.write().unblock(seed)
+    }
+}
+
+impl<T: KeyHasher, B: BlockList> From<Verifier<T, B>> for SharedVerifier<T, B> {
+    fn from(verifier: Verifier<T, B>) -> Self {
+        SharedVerifier::new(verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_verifier, generate_key, TestHasher};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    pub fn verifier_should_be_send_and_sync() {
+        assert_send_sync::<Verifier<TestHasher>>();
+        assert_send_sync::<SharedVerifier<TestHasher>>();
+    }
+
+    #[test]
+    pub fn shared_verifier_should_verify_concurrently() {
+        // Given
+        let verifier = SharedVerifier::new(create_verifier());
+        let key = generate_key(12345);
+
+        // When
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let verifier = verifier.clone();
+                let key = key.clone();
+                std::thread::spawn(move || (0..100).all(|_| verifier.verify(&key) == Status::Valid))
+            })
+            .collect();
+
+        // Then
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    pub fn blocking_through_clone_should_block_for_all_handles() {
+        // Given
+        let verifier = SharedVerifier::from(create_verifier());
+        let other = verifier.clone();
+        let key = generate_key(12345);
+
+        // When
+        other.block(12345);
+        let blocked = verifier.verify(&key);
+        let unblocked = other.unblock(12345);
+
+        // Then
+        assert_eq!(Status::Blocked, blocked);
+        assert_eq!(UnblockResult::Unblocked, unblocked);
+        assert_eq!(Status::Valid, verifier.verify(&key));
+    }
+}
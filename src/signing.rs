@@ -0,0 +1,204 @@
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    BlockList, Claims, Generator, KeyFields, KeyHasher, LicenseKey, Status, Verifier,
+    SIGNATURE_BYTE_LENGTH,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs and verifies [`Claims`] embedded in license keys
+/// using HMAC-SHA256 and a secret key.
+///
+/// The generator and the verifier must use the same secret.
+///
+/// [`Claims`]: struct.Claims.html
+pub struct ClaimSigner {
+    secret: Vec<u8>,
+}
+
+impl ClaimSigner {
+    /// Creates a new claim signer with the specified secret.
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            secret: secret.to_vec(),
+        }
+    }
+
+    fn mac(&self, seed: u64, claims: &Claims) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.secret).expect("HMAC accepts any key size");
+        mac.update(&seed.to_be_bytes());
+        mac.update(&claims.to_bytes());
+        mac
+    }
+
+    pub(crate) fn sign(&self, seed: u64, claims: &Claims) -> [u8; SIGNATURE_BYTE_LENGTH as usize] {
+        let mut signature = [0; SIGNATURE_BYTE_LENGTH as usize];
+        let result = self.mac(seed, claims).finalize().into_bytes();
+        signature.copy_from_slice(&result[..SIGNATURE_BYTE_LENGTH as usize]);
+        signature
+    }
+
+    pub(crate) fn verify(&self, seed: u64, claims: &Claims, signature: &[u8]) -> bool {
+        self.mac(seed, claims)
+            .verify_truncated_left(signature)
+            .is_ok()
+    }
+}
+
+impl<T: KeyHasher> Generator<T> {
+    /// Creates a new license key with the specified seed and
+    /// claims signed by the provided [`ClaimSigner`].
+    ///
+    /// [`ClaimSigner`]: struct.ClaimSigner.html
+    pub fn generate_with_claims(
+        &self,
+        seed: u64,
+        claims: Claims,
+        signer: &ClaimSigner,
+    ) -> LicenseKey {
+        let signature = signer.sign(seed, &claims);
+        self.generate_with_fields(
+            seed,
+            KeyFields {
+                claims: Some((claims, signature)),
+                ..KeyFields::default()
+            },
+        )
+    }
+}
+
+impl<T: KeyHasher, B: BlockList> Verifier<T, B> {
+    /// Perform verification on the provided license key
+    /// and its claims signed by the provided [`ClaimSigner`].
+    ///
+    /// If the key doesn't contain any claims, it's considered invalid.
+    /// If the claims have been tampered with, the key is considered forged.
+    ///
+    /// [`ClaimSigner`]: struct.ClaimSigner.html
+    pub fn verify_with_claims(&self, key: &LicenseKey, signer: &ClaimSigner) -> Status {
+        let status = self.verify(key);
+        if !status.is_valid() {
+            return status;
+        }
+
+        match (key.claims(), key.get_claims_signature()) {
+            (Some(claims), Some(signature)) => {
+                if !signer.verify(key.get_seed(&self.hasher), &claims, signature) {
+                    // The checksum was correct but the signature
+                    // doesn't match, so the claims have been forged.
+                    return Status::Forged;
+                }
+            }
+            _ => return Status::Invalid,
+        }
+
+        status
+    }
+}
+
+/// Wipes the secret of the claim signer.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ClaimSigner {
+    fn zeroize(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+/// Wipes the secret of the claim signer when it's dropped.
+#[cfg(feature = "zeroize")]
+impl Drop for ClaimSigner {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for ClaimSigner {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier};
+    use crate::{calculate_checksum, CHECKSUM_BYTE_LENGTH};
+
+    fn create_claims() -> Claims {
+        Claims {
+            expires_at: 1_700_000_000,
+            features: 0b101,
+            product: 42,
+        }
+    }
+
+    fn tamper(key: &LicenseKey, index: usize) -> LicenseKey {
+        // Flip a byte and recalculate the checksum
+        // so that the key passes the checksum check.
+        let mut bytes = key.get_bytes();
+        bytes[index] ^= 0xFF;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        LicenseKey::new(bytes, key.layout())
+    }
+
+    #[test]
+    pub fn key_with_untouched_claims_should_be_valid() {
+        // Given
+        let signer = ClaimSigner::new(b"secret");
+        let key = create_generator().generate_with_claims(12345, create_claims(), &signer);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_claims(&key, &signer);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+        assert_eq!(Some(create_claims()), key.claims());
+    }
+
+    #[test]
+    pub fn key_with_tampered_claims_should_be_forged() {
+        // Given
+        let signer = ClaimSigner::new(b"secret");
+        let key = create_generator().generate_with_claims(12345, create_claims(), &signer);
+        let verifier = create_verifier();
+
+        for index in 8..18 {
+            // When
+            let result = verifier.verify_with_claims(&tamper(&key, index), &signer);
+
+            // Then
+            assert_eq!(Status::Forged, result);
+        }
+    }
+
+    #[test]
+    pub fn key_signed_with_other_secret_should_be_forged() {
+        // Given
+        let signer = ClaimSigner::new(b"secret");
+        let key = create_generator().generate_with_claims(12345, create_claims(), &signer);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_claims(&key, &ClaimSigner::new(b"other"));
+
+        // Then
+        assert_eq!(Status::Forged, result);
+    }
+
+    #[test]
+    pub fn key_without_claims_should_be_invalid() {
+        // Given
+        let signer = ClaimSigner::new(b"secret");
+        let key = create_generator().generate(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verifier.verify_with_claims(&key, &signer);
+
+        // Then
+        assert_eq!(Status::Invalid, result);
+    }
+}
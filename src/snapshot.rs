@@ -0,0 +1,137 @@
+use crate::detached::verify_signature;
+use crate::{ByteCheck, KeyHasher, Verifier};
+
+/// A signed snapshot of a verifier configuration.
+///
+/// Storing the verifier configuration as a signed snapshot makes it
+/// possible to detect whether the configuration itself has been tampered
+/// with, and not only the license key. Call [`Snapshot::verify_self`]
+/// before creating a verifier using [`Snapshot::verifier`].
+///
+/// The signature is an Ed25519 signature over [`Snapshot::message`].
+///
+/// [`Snapshot::verify_self`]: struct.Snapshot.html#method.verify_self
+/// [`Snapshot::verifier`]: struct.Snapshot.html#method.verifier
+/// [`Snapshot::message`]: struct.Snapshot.html#method.message
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    checks: Vec<ByteCheck>,
+    blocklist: Vec<u64>,
+    blocked_ranges: Vec<(u64, u64)>,
+    signature: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Creates a new snapshot from a verifier configuration
+    /// and a signature over the configuration.
+    pub fn new(
+        checks: Vec<ByteCheck>,
+        blocklist: Vec<u64>,
+        blocked_ranges: Vec<(u64, u64)>,
+        signature: &[u8],
+    ) -> Self {
+        Self {
+            checks,
+            blocklist,
+            blocked_ranges,
+            signature: signature.to_vec(),
+        }
+    }
+
+    /// Gets the message that the snapshot signature is calculated over.
+    pub fn message(&self) -> Vec<u8> {
+        let mut message = Vec::new();
+        message.extend_from_slice(&(self.checks.len() as u32).to_be_bytes());
+        for check in self.checks.iter() {
+            message.push(check.ordinal);
+            message.extend_from_slice(&check.a.to_be_bytes());
+            message.extend_from_slice(&check.b.to_be_bytes());
+            message.extend_from_slice(&check.c.to_be_bytes());
+            message.push(check.decoy as u8);
+        }
+        message.extend_from_slice(&(self.blocklist.len() as u32).to_be_bytes());
+        for seed in self.blocklist.iter() {
+            message.extend_from_slice(&seed.to_be_bytes());
+        }
+        message.extend_from_slice(&(self.blocked_ranges.len() as u32).to_be_bytes());
+        for (start, end) in self.blocked_ranges.iter() {
+            message.extend_from_slice(&start.to_be_bytes());
+            message.extend_from_slice(&end.to_be_bytes());
+        }
+        message
+    }
+
+    /// Verifies that the snapshot was signed by the owner of the
+    /// provided Ed25519 public key and hasn't been tampered with.
+    pub fn verify_self(&self, public_key: &[u8]) -> bool {
+        verify_signature(&self.message(), &self.signature, public_key)
+    }
+
+    /// Creates a verifier from the snapshot with the provided hasher.
+    ///
+    /// This doesn't verify the snapshot, so make sure to
+    /// call [`Snapshot::verify_self`] first.
+    ///
+    /// [`Snapshot::verify_self`]: struct.Snapshot.html#method.verify_self
+    pub fn verifier<T: KeyHasher>(&self, hasher: T) -> Verifier<T> {
+        let mut verifier = Verifier::new(hasher, self.checks.clone());
+        for seed in self.blocklist.iter() {
+            verifier.block(*seed);
+        }
+        for (start, end) in self.blocked_ranges.iter() {
+            verifier.block_range(*start, *end);
+        }
+        verifier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{generate_key, TestHasher};
+    use crate::Status;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn create_snapshot(signing_key: &SigningKey, blocklist: Vec<u64>) -> Snapshot {
+        let checks = vec![
+            ByteCheck::new(0, (114, 83, 170)),
+            ByteCheck::new(2, (69, 14, 202)),
+        ];
+        let unsigned = Snapshot::new(checks, blocklist, Vec::new(), &[]);
+        let signature = signing_key.sign(&unsigned.message());
+        Snapshot {
+            signature: signature.to_bytes().to_vec(),
+            ..unsigned
+        }
+    }
+
+    #[test]
+    pub fn valid_snapshot_should_yield_working_verifier() {
+        // Given
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let snapshot = create_snapshot(&signing_key, vec![54321]);
+
+        // When
+        let result = snapshot.verify_self(signing_key.verifying_key().as_bytes());
+
+        // Then
+        let verifier = snapshot.verifier(TestHasher::default());
+        assert!(result);
+        assert_eq!(Status::Valid, verifier.verify(&generate_key(12345)));
+        assert_eq!(Status::Blocked, verifier.verify(&generate_key(54321)));
+    }
+
+    #[test]
+    pub fn tampered_snapshot_should_fail_verification() {
+        // Given
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let mut snapshot = create_snapshot(&signing_key, vec![54321]);
+        snapshot.blocklist.clear();
+
+        // When
+        let result = snapshot.verify_self(signing_key.verifying_key().as_bytes());
+
+        // Then
+        assert!(!result);
+    }
+}
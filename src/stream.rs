@@ -0,0 +1,429 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{
+    compute_checksum, constant_time_eq, constant_time_mismatch, BlockList, ChecksumState,
+    KeyHasher, KeyLayout, LicenseKey, MemoryBlockList, Status, Verifier,
+};
+
+/// Verifies a license key that arrives as a stream of bytes,
+/// without buffering the whole license key.
+///
+/// Only the bytes in front of the payload (such as the seed) and
+/// the trailing bytes that might be the checksum are buffered, unless
+/// the verifier uses a custom [`Checksum`] which needs every byte.
+/// Create a stream verifier by calling [`Verifier::stream`].
+///
+/// [`Checksum`]: trait.Checksum.html
+/// [`Verifier::stream`]: struct.Verifier.html#method.stream
+pub struct StreamVerifier<'a, T: KeyHasher, B: BlockList = MemoryBlockList> {
+    verifier: &'a Verifier<T, B>,
+    layout: KeyLayout,
+    prefix: Vec<u8>,
+    expected: Vec<Vec<u32>>,
+    differences: Vec<Vec<u32>>,
+    selected: Vec<bool>,
+    pending: Vec<u8>,
+    checksum: ChecksumState,
+    body: Vec<u8>,
+    length: usize,
+}
+
+impl<T: KeyHasher, B: BlockList> Verifier<T, B> {
+    /// Creates a [`StreamVerifier`] for license keys with the default layout.
+    ///
+    /// [`StreamVerifier`]: struct.StreamVerifier.html
+    pub fn stream(&self) -> StreamVerifier<'_, T, B> {
+        self.stream_with_layout(KeyLayout::default())
+    }
+
+    /// Creates a [`StreamVerifier`] for license keys with the specified layout.
+    ///
+    /// [`StreamVerifier`]: struct.StreamVerifier.html
+    pub fn stream_with_layout(&self, layout: KeyLayout) -> StreamVerifier<'_, T, B> {
+        StreamVerifier {
+            verifier: self,
+            layout,
+            prefix: Vec::with_capacity(layout.payload_offset()),
+            expected: Vec::new(),
+            differences: self.hashers().map(|_| vec![0; self.checks.len()]).collect(),
+            selected: self
+                .select_checks()
+                .unwrap_or_else(|| vec![true; self.checks.len()]),
+            pending: Vec::with_capacity(layout.checksum_length() + 1),
+            checksum: ChecksumState::new(),
+            body: Vec::new(),
+            length: 0,
+        }
+    }
+}
+
+impl<'a, T: KeyHasher, B: BlockList> StreamVerifier<'a, T, B> {
+    /// Feeds the next bytes of the license key to the verifier.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        for byte in bytes.iter() {
+            // Hold back the last bytes since they might be the checksum
+            self.pending.push(*byte);
+            if self.pending.len() > self.layout.checksum_length() {
+                let byte = self.pending.remove(0);
+                self.consume(byte);
+            }
+        }
+    }
+
+    fn consume(&mut self, byte: u8) {
+        self.checksum.update(byte);
+        if self.verifier.checksum.is_some() {
+            self.body.push(byte);
+        }
+        let position = self.length;
+        self.length += 1;
+
+        let payload_offset = self.layout.payload_offset();
+        if position < payload_offset {
+            self.prefix.push(byte);
+            if self.prefix.len() == payload_offset {
+                // We know the seed now, so calculate the expected bytes
+                let verifier = self.verifier;
+                let key = self.prefix_key();
+                let segment_length = self.layout.segment_length;
+                let expected = verifier
+                    .hashers()
+                    .map(|hasher| {
+                        let hash_seed = verifier.hash_seed(&key, hasher)?;
+                        let expected = verifier
+                            .checks
+                            .iter()
+                            .map(|check| check.expected(hasher, hash_seed, segment_length));
+                        Some(expected.collect())
+                    })
+                    .collect::<Option<Vec<Vec<u32>>>>();
+                if let Some(expected) = expected {
+                    self.expected = expected;
+                }
+            }
+            return;
+        }
+
+        // Compare the byte with the same byte of the expected segment
+        let segment_length = self.layout.segment_length.bytes();
+        let ordinal = (position - payload_offset) / segment_length;
+        let shift = 8 * (segment_length - 1 - (position - payload_offset) % segment_length);
+        for (expected, differences) in self.expected.iter().zip(self.differences.iter_mut()) {
+            let checks = self.verifier.checks.iter().zip(expected.iter());
+            for ((check, expected), difference) in checks.zip(differences.iter_mut()) {
+                if check.ordinal as usize == ordinal {
+                    *difference |= (byte as u32 ^ (expected >> shift)) & 0xFF;
+                }
+            }
+        }
+    }
+
+    fn prefix_key(&self) -> LicenseKey {
+        LicenseKey::new(self.prefix.clone(), self.layout)
+    }
+
+    /// Finishes the verification and returns the status of the license key.
+    pub fn finish(self) -> Status {
+        let status = self.finish_internal();
+        self.verifier.conceal(status)
+    }
+
+    fn finish_internal(&self) -> Status {
+        let payload_offset = self.layout.payload_offset();
+        let checksum_length = self.layout.checksum_length();
+        if self.length < payload_offset || self.pending.len() < checksum_length {
+            return Status::Invalid;
+        }
+        let payload_length = (self.length - payload_offset) / self.layout.segment_length.bytes();
+
+        // Validate the length
+        if let Some(expected) = self.verifier.payload_length {
+            if self.length + checksum_length != self.layout.key_length(expected) {
+                return Status::Invalid;
+            }
+        }
+
+        // Validate the checksum
+        let checksum = match self.verifier.checksum.as_deref() {
+            Some(custom) => compute_checksum(
+                Some(custom),
+                &self.body,
+                self.verifier.fold_length,
+                self.layout.long_checksum,
+            ),
+            None => {
+                let mut checksum = self.checksum.clone();
+                if self.verifier.fold_length {
+                    checksum.fold_length(self.length);
+                }
+                if self.layout.long_checksum {
+                    checksum.finish_long().to_vec()
+                } else {
+                    checksum.finish().to_vec()
+                }
+            }
+        };
+        if !constant_time_eq(&checksum, &self.pending) {
+            return Status::Invalid;
+        }
+
+        // Validate the seed checksum
+        let key = self.prefix_key();
+        if self.layout.seed_checksum && !key.seed_checksum_ok() {
+            return Status::Invalid;
+        }
+
+        // Blocked key?
+        if self.verifier.blocked(&key).is_some() {
+            return Status::Blocked;
+        }
+
+        // If a checked byte wasn't part of
+        // the license, the license is invalid.
+        for check in self.verifier.checks.iter() {
+            if check.ordinal as usize >= payload_length {
+                return Status::Invalid;
+            }
+        }
+
+        // Without the machine identifier, the expected bytes are unknown
+        if self.layout.machine && self.verifier.machine_id.is_none() {
+            return Status::WrongMachine;
+        }
+
+        let mismatches = self
+            .differences
+            .iter()
+            .map(|differences| {
+                differences
+                    .iter()
+                    .zip(self.selected.iter())
+                    .filter(|(_, selected)| **selected)
+                    .map(|(difference, _)| constant_time_mismatch(*difference, 0))
+                    .sum()
+            })
+            .min()
+            .unwrap_or(0);
+        if mismatches > self.verifier.allowed_mismatches() {
+            if self.layout.machine {
+                return Status::WrongMachine;
+            }
+            return Status::Forged;
+        }
+
+        let now = self.verifier.clock.as_ref().map(|clock| clock.now_unix());
+        let claims = key.claims();
+        self.verifier
+            .expiry_status(&key, key.expires_at(), claims.as_ref(), now)
+            .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier, generate_key, TestHasher};
+    use crate::{
+        calculate_checksum, Crc16, FixedClock, Generator, SegmentLength, CHECKSUM_BYTE_LENGTH,
+    };
+
+    fn verify_in_chunks(verifier: &Verifier<TestHasher>, key: &LicenseKey, at: usize) -> Status {
+        let bytes = key.get_bytes();
+        let mut stream = verifier.stream_with_layout(key.layout());
+        stream.feed(&bytes[..at]);
+        stream.feed(&bytes[at..]);
+        stream.finish()
+    }
+
+    #[test]
+    pub fn valid_key_fed_in_chunks_should_be_valid() {
+        // Given
+        let key = generate_key(12345);
+        let verifier = create_verifier();
+
+        for at in 0..key.get_bytes().len() {
+            // When
+            let result = verify_in_chunks(&verifier, &key, at);
+
+            // Then
+            assert_eq!(verifier.verify(&key), result);
+        }
+    }
+
+    #[test]
+    pub fn forged_key_fed_in_chunks_should_be_forged() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let key = forger.generate(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verify_in_chunks(&verifier, &key, 5);
+
+        // Then
+        assert_eq!(Status::Forged, result);
+        assert_eq!(verifier.verify(&key), result);
+    }
+
+    #[test]
+    pub fn blocked_device_key_fed_in_chunks_should_be_blocked() {
+        // Given
+        let key = create_generator().generate_device_key(12345, 2);
+        let mut verifier = create_verifier();
+        verifier.block_device(12345, 2);
+
+        // When
+        let result = verify_in_chunks(&verifier, &key, 10);
+
+        // Then
+        assert_eq!(Status::Blocked, result);
+    }
+
+    #[test]
+    pub fn truncated_key_fed_in_chunks_should_be_invalid() {
+        // Given
+        let key = generate_key(12345);
+        let verifier = create_verifier();
+        let mut stream = verifier.stream();
+
+        // When
+        stream.feed(&key.get_bytes()[..6]);
+        let result = stream.finish();
+
+        // Then
+        assert_eq!(Status::Invalid, result);
+    }
+
+    #[test]
+    pub fn long_checksum_key_fed_in_chunks_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_long_checksum(true);
+        let key = generator.generate(12345);
+        let verifier = create_verifier();
+
+        // When
+        let result = verify_in_chunks(&verifier, &key, 10);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn key_with_custom_checksum_fed_in_chunks_should_be_valid() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_checksum(Crc16);
+        let key = generator.generate(12345);
+        let mut verifier = create_verifier();
+        verifier.use_checksum(Crc16);
+
+        // When
+        let result = verify_in_chunks(&verifier, &key, 3);
+
+        // Then
+        assert_eq!(Status::Valid, result);
+    }
+
+    #[test]
+    pub fn key_with_wide_segments_fed_in_chunks_should_be_verified() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_segment_length(SegmentLength::Two);
+        let key = generator.generate(12345);
+        let mut bytes = key.get_bytes();
+        bytes[13] ^= 0x01;
+        let body = bytes.len() - CHECKSUM_BYTE_LENGTH as usize;
+        let checksum = calculate_checksum(&bytes[..body], false, false);
+        bytes[body..].copy_from_slice(&checksum);
+        let tampered = LicenseKey::new(bytes, key.layout());
+        let verifier = create_verifier();
+
+        // When
+        let valid = verify_in_chunks(&verifier, &key, 9);
+        let forged = verify_in_chunks(&verifier, &tampered, 9);
+
+        // Then
+        assert_eq!(Status::Valid, valid);
+        assert_eq!(Status::Forged, forged);
+    }
+
+    #[test]
+    pub fn machine_key_fed_in_chunks_should_be_checked_against_machine() {
+        // Given
+        let key = create_generator().generate_for_machine(12345, 7);
+        let mut right = create_verifier();
+        right.use_machine_id(7);
+        let mut wrong = create_verifier();
+        wrong.use_machine_id(8);
+
+        // When
+        let on_right_machine = verify_in_chunks(&right, &key, 4);
+        let on_wrong_machine = verify_in_chunks(&wrong, &key, 4);
+        let on_unknown_machine = verify_in_chunks(&create_verifier(), &key, 4);
+
+        // Then
+        assert_eq!(Status::Valid, on_right_machine);
+        assert_eq!(Status::WrongMachine, on_wrong_machine);
+        assert_eq!(Status::WrongMachine, on_unknown_machine);
+    }
+
+    #[test]
+    pub fn key_from_previous_hasher_fed_in_chunks_should_be_valid() {
+        // Given
+        let verifier = crate::tests::create_rotating_verifier();
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+
+        for (key, expected) in [
+            (generate_key(12345), Status::Valid),
+            (forger.generate(12345), Status::Forged),
+        ]
+        .iter()
+        {
+            // When
+            let bytes = key.get_bytes();
+            let mut stream = verifier.stream();
+            stream.feed(&bytes[..5]);
+            stream.feed(&bytes[5..]);
+            let result = stream.finish();
+
+            // Then
+            assert_eq!(*expected, result);
+        }
+    }
+
+    #[test]
+    pub fn expired_key_fed_in_chunks_should_be_expired() {
+        // Given
+        let generator = create_generator();
+        let key = generator.generate_with_expiry(12345, 1_800_000_000);
+        let trial = generator.generate_trial(12345, 1_800_000_000);
+        let mut verifier = create_verifier();
+        verifier.use_clock(FixedClock(1_800_000_001));
+
+        // When
+        let expired = verify_in_chunks(&verifier, &key, 5);
+        let expired_trial = verify_in_chunks(&verifier, &trial, 5);
+
+        // Then
+        assert_eq!(Status::Expired, expired);
+        assert_eq!(Status::Expired, expired_trial);
+        assert_eq!(verifier.verify(&key), expired);
+        assert_eq!(verifier.verify(&trial), expired_trial);
+    }
+
+    #[test]
+    pub fn active_trial_fed_in_chunks_should_be_trial() {
+        // Given
+        let trial = create_generator().generate_trial(12345, 1_800_000_000);
+        let mut verifier = create_verifier();
+        verifier.use_clock(FixedClock(1_800_000_000 - 86_400));
+
+        // When
+        let result = verify_in_chunks(&verifier, &trial, 5);
+
+        // Then
+        assert_eq!(Status::Trial { days_remaining: 1 }, result);
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+
+use crate::{ByteCheck, Checksum, Clock, KeyHasher, Verifier, VerifierError};
+
+/// A builder that creates a [`Verifier`] step by step, which makes
+/// it easy to add byte checks and blocked seeds conditionally.
+/// Create a builder by calling [`Verifier::builder`].
+///
+/// ```
+/// use license_key::*;
+/// # struct DummyHasher {}
+/// # impl KeyHasher for DummyHasher {
+/// #     fn hash(&self, seed: u64, a: u64, b: u64, c: u64) -> u8 {
+/// #         (seed ^ a ^ b ^ c) as u8
+/// #     }
+/// # }
+/// # let generator = Generator::new(DummyHasher {}, vec![(1, 2, 3), (4, 5, 6)]);
+/// # let strict = true;
+/// let mut builder = Verifier::builder(DummyHasher {})
+///     .check(0, (1, 2, 3))
+///     .block(12345);
+/// if strict {
+///     builder = builder.check(1, (4, 5, 6));
+/// }
+/// let verifier = builder.build();
+///
+/// let key = generator.generate(1234567891011121314_u64);
+/// assert_eq!(Status::Valid, verifier.verify(&key));
+/// ```
+///
+/// [`Verifier`]: struct.Verifier.html
+/// [`Verifier::builder`]: struct.Verifier.html#method.builder
+pub struct VerifierBuilder<T: KeyHasher> {
+    verifier: Verifier<T>,
+}
+
+impl<T: KeyHasher> Verifier<T> {
+    /// Creates a [`VerifierBuilder`] for a verifier with the specified hasher.
+    ///
+    /// [`VerifierBuilder`]: struct.VerifierBuilder.html
+    pub fn builder(hasher: T) -> VerifierBuilder<T> {
+        VerifierBuilder {
+            verifier: Verifier::new(hasher, Vec::new()),
+        }
+    }
+}
+
+impl<T: KeyHasher> VerifierBuilder<T> {
+    /// Adds a byte check for the specified ordinal and
+    /// initialization vector triplet. See [`ByteCheck::new`].
+    ///
+    /// [`ByteCheck::new`]: struct.ByteCheck.html#method.new
+    pub fn check(mut self, ordinal: u8, iv: (u64, u64, u64)) -> Self {
+        self.verifier.checks.push(ByteCheck::new(ordinal, iv));
+        self
+    }
+
+    /// Adds a byte check for a decoy byte. See [`ByteCheck::decoy`].
+    ///
+    /// [`ByteCheck::decoy`]: struct.ByteCheck.html#method.decoy
+    pub fn decoy(mut self, ordinal: u8, iv: (u64, u64, u64)) -> Self {
+        self.verifier.checks.push(ByteCheck::decoy(ordinal, iv));
+        self
+    }
+
+    /// Blocks the specified seed. See [`Verifier::block`].
+    ///
+    /// [`Verifier::block`]: struct.Verifier.html#method.block
+    pub fn block(mut self, seed: u64) -> Self {
+        self.verifier.block(seed);
+        self
+    }
+
+    /// Blocks all seeds between `start` and `end` (inclusive).
+    /// See [`Verifier::block_range`].
+    ///
+    /// [`Verifier::block_range`]: struct.Verifier.html#method.block_range
+    pub fn block_range(mut self, start: u64, end: u64) -> Self {
+        self.verifier.block_range(start, end);
+        self
+    }
+
+    /// Sets the clock that is used to get the current time.
+    /// See [`Verifier::use_clock`].
+    ///
+    /// [`Verifier::use_clock`]: struct.Verifier.html#method.use_clock
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.verifier.use_clock(clock);
+        self
+    }
+
+    /// Sets the [`Checksum`] algorithm that license keys are validated
+    /// with. See [`Verifier::use_checksum`].
+    ///
+    /// [`Checksum`]: trait.Checksum.html
+    /// [`Verifier::use_checksum`]: struct.Verifier.html#method.use_checksum
+    pub fn checksum(mut self, checksum: impl Checksum + 'static) -> Self {
+        self.verifier.use_checksum(checksum);
+        self
+    }
+
+    /// Sets the expected payload length.
+    /// See [`Verifier::expect_payload_length`].
+    ///
+    /// [`Verifier::expect_payload_length`]: struct.Verifier.html#method.expect_payload_length
+    pub fn payload_length(mut self, length: usize) -> Self {
+        self.verifier.expect_payload_length(length);
+        self
+    }
+
+    /// Builds the verifier.
+    pub fn build(self) -> Verifier<T> {
+        self.verifier
+    }
+
+    /// Builds the verifier, unless the payload length has been set and
+    /// any of the byte checks has an ordinal outside of the payload.
+    /// See [`Verifier::try_new`].
+    ///
+    /// [`Verifier::try_new`]: struct.Verifier.html#method.try_new
+    pub fn try_build(self) -> Result<Verifier<T>, VerifierError> {
+        self.verifier.validate_ordinals()?;
+        Ok(self.verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_generator, create_verifier, generate_key, TestHasher};
+    use crate::{Crc16, FixedClock, Generator, Status};
+
+    #[test]
+    pub fn builder_should_create_equivalent_verifier() {
+        // Given
+        let forger = Generator::new(TestHasher::default(), vec![(1, 2, 3), (4, 5, 6), (7, 8, 9)]);
+        let keys = vec![
+            generate_key(12345),
+            generate_key(67890),
+            forger.generate(12345),
+        ];
+        let mut expected = create_verifier();
+        expected.block(67890);
+
+        // When
+        let verifier = Verifier::builder(TestHasher::default())
+            .check(0, (114, 83, 170))
+            .check(2, (69, 14, 202))
+            .block(67890)
+            .build();
+
+        // Then
+        let statuses = vec![Status::Valid, Status::Blocked, Status::Forged];
+        assert_eq!(statuses, expected.verify_many(keys.clone()));
+        assert_eq!(statuses, verifier.verify_many(keys));
+    }
+
+    #[test]
+    pub fn builder_should_set_clock_and_checksum() {
+        // Given
+        let mut generator = create_generator();
+        generator.use_checksum(Crc16);
+        let key = generator.generate_with_expiry(12345, 1_700_000_000);
+
+        // When
+        let verifier = Verifier::builder(TestHasher::default())
+            .check(0, (114, 83, 170))
+            .clock(FixedClock(1_800_000_000))
+            .checksum(Crc16)
+            .build();
+
+        // Then
+        assert_eq!(Status::Expired, verifier.verify(&key));
+    }
+
+    #[test]
+    pub fn builder_with_out_of_range_decoy_should_fail() {
+        // Given
+        let builder = Verifier::builder(TestHasher::default())
+            .check(0, (114, 83, 170))
+            .decoy(7, (1, 2, 3))
+            .payload_length(create_generator().payload_length());
+
+        // When
+        let result = builder.try_build();
+
+        // Then
+        let expected = VerifierError::OrdinalOutOfRange {
+            ordinal: 7,
+            payload_length: 4,
+        };
+        assert_eq!(Some(expected), result.err());
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+
+use crate::{ByteCheck, HexFormat, HmacHasher, KeyLayout, LicenseKey, Status, Verifier};
+
+/// A verifier created from a line-based configuration, together with
+/// the layout of the license keys that it verifies.
+pub(crate) struct ConfiguredVerifier {
+    pub verifier: Verifier<HmacHasher>,
+    pub layout: KeyLayout,
+}
+
+impl ConfiguredVerifier {
+    /// Verifies the specified hex license key at the specified time
+    /// (as a Unix timestamp), if any. A license key that can't be
+    /// parsed is invalid.
+    pub fn verify(&self, hex_key: &str, now: Option<u64>) -> Status {
+        let key = match LicenseKey::parse_with_layout(hex_key, &HexFormat, self.layout) {
+            Ok(key) => key,
+            Err(_) => return Status::Invalid,
+        };
+        match now {
+            Some(now) => self.verifier.verify_at(&key, now),
+            None => self.verifier.verify(&key),
+        }
+    }
+}
+
+/// Parses a line-based verifier configuration, which is the format
+/// that verifiers are created from by the C and WebAssembly APIs.
+///
+/// Each line contains one directive: `secret <hex>` (required),
+/// `check <ordinal> <a> <b> <c>`, `decoy <ordinal> <a> <b> <c>`,
+/// `block <seed>` or `layout <field>...`, where the fields are the
+/// optional fields of the `KeyLayout` (such as `expiry` and `flags`).
+/// Empty lines and lines starting with `#` are ignored. Returns `None`
+/// if the configuration isn't valid.
+pub(crate) fn parse_config(config: &str) -> Option<ConfiguredVerifier> {
+    let mut secret = None;
+    let mut checks = Vec::new();
+    let mut blocked = Vec::new();
+    let mut layout = KeyLayout::default();
+    for line in config.lines().map(|line| line.trim()) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let directive = parts.next()?;
+        let arguments: Vec<&str> = parts.collect();
+        match (directive, arguments.as_slice()) {
+            ("secret", [value]) => secret = Some(hex::decode(value).ok()?),
+            ("check", [ordinal, a, b, c]) => {
+                checks.push(ByteCheck::new(ordinal.parse().ok()?, parse_iv(a, b, c)?))
+            }
+            ("decoy", [ordinal, a, b, c]) => {
+                checks.push(ByteCheck::decoy(ordinal.parse().ok()?, parse_iv(a, b, c)?))
+            }
+            ("block", [seed]) => blocked.push(seed.parse().ok()?),
+            ("layout", fields) => {
+                for field in fields.iter() {
+                    parse_layout_field(&mut layout, field)?;
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    let mut verifier = Verifier::new(HmacHasher::new(&secret?), checks);
+    verifier.import_blocklist(blocked);
+    Some(ConfiguredVerifier { verifier, layout })
+}
+
+fn parse_layout_field(layout: &mut KeyLayout, field: &str) -> Option<()> {
+    let enabled = match field {
+        "seed_checksum" => &mut layout.seed_checksum,
+        "version" => &mut layout.version,
+        "flags" => &mut layout.flags,
+        "device_index" => &mut layout.device_index,
+        "region" => &mut layout.region,
+        "expiry" => &mut layout.expiry,
+        "issue_date" => &mut layout.issue_date,
+        "features" => &mut layout.features,
+        "long_checksum" => &mut layout.long_checksum,
+        _ => return None,
+    };
+    *enabled = true;
+    Some(())
+}
+
+fn parse_iv(a: &str, b: &str, c: &str) -> Option<(u64, u64, u64)> {
+    Some((a.parse().ok()?, b.parse().ok()?, c.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn config_should_create_verifier() {
+        // Given
+        let config = "
+            # The secret is 'secret'
+            secret 736563726574
+            check 0 114 83 170
+            decoy 3 1 2 3
+            block 67890
+            layout expiry flags
+        ";
+
+        // When
+        let configured = parse_config(config).unwrap();
+
+        // Then
+        let verifier = &configured.verifier;
+        assert_eq!(2, verifier.checks().len());
+        assert!(verifier.checks()[1].decoy);
+        assert!(verifier.is_blocked(67890));
+        assert!(configured.layout.expiry);
+        assert!(configured.layout.flags);
+        assert!(!configured.layout.version);
+    }
+
+    #[test]
+    pub fn config_with_unknown_layout_field_should_be_rejected() {
+        assert!(parse_config("secret 736563726574\nlayout expiry color").is_none());
+    }
+}
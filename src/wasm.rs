@@ -0,0 +1,176 @@
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::*;
+
+use crate::verifier_config::{parse_config, ConfiguredVerifier};
+use crate::Status;
+
+/// A license key verifier that can be used from JavaScript when the crate
+/// is compiled to WebAssembly with the `wasm` feature.
+///
+/// Only verification is exposed, which means that the generator (and
+/// the full initialization vector) stays on the server. The verifier is
+/// created from a configuration with one directive per line: `secret <hex>`
+/// (required) sets the secret of the [`HmacHasher`], `check <ordinal> <a> <b> <c>`
+/// adds a byte check, `decoy <ordinal> <a> <b> <c>` adds a byte check for a
+/// decoy byte, `block <seed>` blocks a seed and `layout <field>...` sets
+/// the optional fields of the license keys, such as `layout expiry flags`
+/// for trial license keys.
+///
+/// Trial and expiring license keys are only accepted when they're verified
+/// with the current time using `verifyAt`, since the browser has no clock
+/// that the verifier can use on its own.
+///
+/// ```js
+/// import { WasmVerifier } from "./license_key.js";
+///
+/// const verifier = WasmVerifier.fromConfig(
+///     "secret 736563726574\ncheck 0 114 83 170\nlayout expiry flags");
+/// const now = Date.now() / 1000;
+/// const status = verifier.verifyAt(key, now);
+/// if (status === "valid" || status === "trial") {
+///     unlock(verifier.daysRemaining(key, now));
+/// }
+/// ```
+///
+/// [`HmacHasher`]: struct.HmacHasher.html
+#[wasm_bindgen]
+pub struct WasmVerifier {
+    configured: ConfiguredVerifier,
+}
+
+#[wasm_bindgen]
+impl WasmVerifier {
+    /// Creates a new verifier from the specified configuration,
+    /// or returns `undefined` if the configuration isn't valid.
+    #[wasm_bindgen(js_name = fromConfig)]
+    pub fn from_config(config: &str) -> Option<WasmVerifier> {
+        parse_config(config).map(|configured| WasmVerifier { configured })
+    }
+
+    /// Verifies the specified hex license key and returns the name of
+    /// the status, such as `"valid"` or `"forged"`. A license key that
+    /// can't be parsed is `"invalid"`.
+    ///
+    /// The current time is unknown, so trial license keys are `"expired"`.
+    /// Use `verifyAt` to verify trial and expiring license keys.
+    pub fn verify(&self, hex_key: &str) -> String {
+        status_name(self.configured.verify(hex_key, None)).to_string()
+    }
+
+    /// Verifies the specified hex license key at the specified time (as
+    /// a Unix timestamp in seconds, such as `Date.now() / 1000`) and returns
+    /// the name of the status. An active trial license key is `"trial"`.
+    #[wasm_bindgen(js_name = verifyAt)]
+    pub fn verify_at(&self, hex_key: &str, now: f64) -> String {
+        status_name(self.configured.verify(hex_key, Some(now as u64))).to_string()
+    }
+
+    /// Gets the number of days remaining of the specified hex trial
+    /// license key at the specified time (as a Unix timestamp in seconds),
+    /// or `undefined` if the license key isn't an active trial.
+    #[wasm_bindgen(js_name = daysRemaining)]
+    pub fn days_remaining(&self, hex_key: &str, now: f64) -> Option<u32> {
+        match self.configured.verify(hex_key, Some(now as u64)) {
+            Status::Trial { days_remaining } => Some(days_remaining),
+            _ => None,
+        }
+    }
+}
+
+fn status_name(status: Status) -> &'static str {
+    match status {
+        Status::Valid => "valid",
+        Status::Invalid => "invalid",
+        Status::Blocked => "blocked",
+        Status::Forged => "forged",
+        Status::RateLimited => "rate_limited",
+        Status::Expired => "expired",
+        Status::WrongRegion => "wrong_region",
+        Status::UnsupportedVersion => "unsupported_version",
+        Status::WrongMachine => "wrong_machine",
+        Status::Trial { .. } => "trial",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Generator, HexFormat, HmacHasher};
+
+    const TRIAL_CONFIG: &str = "secret 736563726574\ncheck 0 114 83 170\nlayout expiry flags";
+
+    fn create_trial_generator() -> Generator<HmacHasher> {
+        let mut generator = Generator::new(HmacHasher::new(b"secret"), vec![(114, 83, 170)]);
+        generator.include_flags(true);
+        generator
+    }
+
+    #[test]
+    pub fn wasm_verifier_should_return_status_names() {
+        // Given
+        let generator = Generator::new(HmacHasher::new(b"secret"), vec![(114, 83, 170)]);
+        let key = generator.generate(12345).serialize(&HexFormat);
+        let verifier =
+            WasmVerifier::from_config("secret 736563726574\ncheck 0 114 83 170\nblock 67890")
+                .unwrap();
+        let blocked = generator.generate(67890).serialize(&HexFormat);
+
+        // When
+        let valid = verifier.verify(&key);
+        let blocked = verifier.verify(&blocked);
+        let invalid = verifier.verify("xyz");
+
+        // Then
+        assert_eq!("valid", valid);
+        assert_eq!("blocked", blocked);
+        assert_eq!("invalid", invalid);
+    }
+
+    #[test]
+    pub fn wasm_verifier_should_verify_trial_keys_at_time() {
+        // Given
+        let generator = create_trial_generator();
+        let trial = generator
+            .generate_trial(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let paid = generator
+            .generate_with_expiry(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let verifier = WasmVerifier::from_config(TRIAL_CONFIG).unwrap();
+        let now = 1_800_000_000.0 - 2.5 * 86_400.0;
+
+        // When
+        let active = verifier.verify_at(&trial, now);
+        let days_remaining = verifier.days_remaining(&trial, now);
+
+        // Then
+        assert_eq!("trial", active);
+        assert_eq!(Some(3), days_remaining);
+        assert_eq!("valid", verifier.verify_at(&paid, now));
+        assert_eq!(None, verifier.days_remaining(&paid, now));
+        assert_eq!("expired", verifier.verify(&trial));
+    }
+
+    #[test]
+    pub fn wasm_verifier_should_reject_expired_keys() {
+        // Given
+        let generator = create_trial_generator();
+        let trial = generator
+            .generate_trial(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let paid = generator
+            .generate_with_expiry(12345, 1_800_000_000)
+            .serialize(&HexFormat);
+        let verifier = WasmVerifier::from_config(TRIAL_CONFIG).unwrap();
+
+        // When
+        let expired_trial = verifier.verify_at(&trial, 1_800_000_001.0);
+        let expired = verifier.verify_at(&paid, 1_800_000_001.0);
+
+        // Then
+        assert_eq!("expired", expired_trial);
+        assert_eq!("expired", expired);
+        assert_eq!(None, verifier.days_remaining(&trial, 1_800_000_001.0));
+    }
+}